@@ -0,0 +1,755 @@
+//! Tests for rorm's query builders which need an actual database to run against
+//!
+//! Every test connects to its own fresh SQLite file and creates the tables it needs using raw sql.
+
+use std::env::temp_dir;
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hasher, RandomState};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::num::NonZeroU64;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use rorm::crud::raw::query_raw;
+use rorm::crud::selector::OptionalPatch;
+use rorm::db::executor::Nothing;
+use rorm::db::Executor;
+use rorm::db::{Database, DatabaseConfiguration, DatabaseDriver};
+use rorm::prelude::*;
+use tracing::field::Visit;
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::DefaultGuard;
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Model)]
+struct User {
+    #[rorm(primary_key)]
+    id: i64,
+
+    #[rorm(max_length = 255)]
+    name: String,
+}
+
+#[derive(Model)]
+struct Comment {
+    #[rorm(primary_key)]
+    id: i64,
+
+    user: Option<ForeignModel<User>>,
+}
+
+/// Connects to a new SQLite database and executes `statements` on it
+async fn setup(statements: &[&str]) -> Database {
+    let filename = temp_dir()
+        .join(format!(
+            "test-rorm-crud-{}.sqlite",
+            RandomState::new().build_hasher().finish()
+        ))
+        .display()
+        .to_string();
+    let db = Database::connect(DatabaseConfiguration::new(DatabaseDriver::SQLite {
+        filename,
+    }))
+    .await
+    .unwrap();
+    for statement in statements {
+        db.execute::<Nothing>(statement.to_string(), Vec::new())
+            .await
+            .unwrap();
+    }
+    db
+}
+
+/// Records the sql of every statement executed on the current thread until the guard is dropped
+fn record_sql() -> (Arc<Mutex<Vec<String>>>, DefaultGuard) {
+    let statements = Arc::new(Mutex::new(Vec::new()));
+    let guard = tracing::subscriber::set_default(SqlRecorder(statements.clone()));
+    (statements, guard)
+}
+
+/// [`Subscriber`] which collects the `sql` field of rorm-db's "Executing statement" events
+struct SqlRecorder(Arc<Mutex<Vec<String>>>);
+impl Subscriber for SqlRecorder {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == "rorm_db::executor"
+    }
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+    fn event(&self, event: &Event<'_>) {
+        struct Visitor<'a>(&'a mut Vec<String>);
+        impl Visit for Visitor<'_> {
+            fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+                if field.name() == "sql" {
+                    self.0.push(value.to_string());
+                }
+            }
+            fn record_debug(&mut self, _field: &tracing::field::Field, _value: &dyn Debug) {}
+        }
+        event.record(&mut Visitor(&mut self.0.lock().unwrap()));
+    }
+    fn enter(&self, _span: &Id) {}
+    fn exit(&self, _span: &Id) {}
+}
+
+const CREATE_USER: &str =
+    "CREATE TABLE user (id INTEGER PRIMARY KEY NOT NULL, name VARCHAR(255) NOT NULL);";
+const CREATE_COMMENT: &str =
+    "CREATE TABLE comment (id INTEGER PRIMARY KEY NOT NULL, user INTEGER REFERENCES user (id));";
+
+#[tokio::test]
+async fn optional_patch_decodes_missing_relation_as_none() {
+    let db = setup(&[CREATE_USER, CREATE_COMMENT]).await;
+    rorm::insert(&db, User)
+        .return_nothing()
+        .single(&User {
+            id: 1,
+            name: "alice".to_string(),
+        })
+        .await
+        .unwrap();
+    rorm::insert(&db, Comment)
+        .return_nothing()
+        .bulk([
+            &Comment {
+                id: 1,
+                user: Some(ForeignModelByField(1)),
+            },
+            &Comment { id: 2, user: None },
+        ])
+        .await
+        .unwrap();
+
+    let comments = rorm::query(
+        &db,
+        (Comment.id, OptionalPatch(Comment.user.query_as(User.name))),
+    )
+    .order_asc(Comment.id)
+    .all()
+    .await
+    .unwrap();
+    assert_eq!(
+        comments,
+        vec![(1, Some("alice".to_string())), (2, None)],
+        "the comment without user should still be returned"
+    );
+}
+
+#[derive(Model)]
+struct File {
+    #[rorm(id)]
+    id: i64,
+
+    #[rorm(max_length = 255)]
+    path: PathBuf,
+}
+
+#[derive(Patch)]
+#[rorm(model = "File")]
+struct FileInsert {
+    path: PathBuf,
+}
+
+const CREATE_FILE: &str = "CREATE TABLE file (id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, path VARCHAR(255) NOT NULL);";
+
+#[tokio::test]
+async fn path_buf_round_trip() {
+    let db = setup(&[CREATE_FILE]).await;
+    let paths = [
+        PathBuf::from("/home/alice/notes.txt"),
+        PathBuf::from(r"C:\Users\alice\notes.txt"),
+    ];
+    for path in &paths {
+        rorm::insert(&db, File)
+            .single(&FileInsert { path: path.clone() })
+            .await
+            .unwrap();
+    }
+
+    let stored = rorm::query(&db, File.path)
+        .order_asc(File.id)
+        .all()
+        .await
+        .unwrap();
+    assert_eq!(stored, paths);
+
+    let found = rorm::query(&db, File.id)
+        .condition(File.path.equals(paths[1].as_path()))
+        .all()
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn path_buf_rejects_invalid_utf8() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let db = setup(&[CREATE_FILE]).await;
+    let path = PathBuf::from(OsStr::from_bytes(b"/tmp/\xff"));
+
+    let error = rorm::insert(&db, File)
+        .return_nothing()
+        .single(&FileInsert { path: path.clone() })
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("not valid UTF-8"), "{error}");
+    assert!(rorm::query(&db, File.id).all().await.unwrap().is_empty());
+
+    // Comparing to such a path fails the same way
+    let result = rorm::query(&db, File.id)
+        .condition(File.path.equals(path.as_path()))
+        .all()
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn insert_many_returns_row_count() {
+    let db = setup(&[CREATE_USER]).await;
+
+    // More rows than fit into a single statement's bind parameters
+    let users: Vec<_> = (0..20_000)
+        .map(|id| User {
+            id,
+            name: format!("user{id}"),
+        })
+        .collect();
+    let inserted = rorm::insert_many(&db, &users).await.unwrap();
+    assert_eq!(inserted, 20_000);
+
+    let (count,) = rorm::query(&db, (User::count_star(),)).one().await.unwrap();
+    assert_eq!(count, 20_000);
+
+    assert_eq!(rorm::insert_many(&db, Vec::<User>::new()).await.unwrap(), 0);
+}
+
+#[derive(Model)]
+struct Log {
+    #[rorm(id)]
+    id: i64,
+
+    level: i32,
+}
+
+#[derive(Patch)]
+#[rorm(model = "Log")]
+struct LogInsert {
+    level: i32,
+}
+
+const CREATE_LOG: &str =
+    "CREATE TABLE log (id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, level INTEGER NOT NULL);";
+
+#[tokio::test]
+async fn delete_in_batches_deletes_all_matching_rows() {
+    let db = setup(&[CREATE_LOG]).await;
+    rorm::insert_many(
+        &db,
+        (0..25).map(|index| LogInsert {
+            level: (index % 5 == 0) as i32,
+        }),
+    )
+    .await
+    .unwrap();
+
+    // 20 matching rows in batches of 7, 7 and 6
+    let (statements, _guard) = record_sql();
+    let deleted = rorm::delete(&db, Log)
+        .delete_in_batches(Log.level.equals(0), NonZeroU64::new(7).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(deleted, 20);
+    let batch = r#"DELETE FROM "log" WHERE ("log".id IN (SELECT __ca FROM (SELECT "log"."id" AS __ca FROM "log" WHERE ("log".level = ?) LIMIT 7) AS __batch)) ;"#;
+    assert_eq!(
+        *statements.lock().unwrap(),
+        [batch; 3],
+        "every batch should be a single statement using a subquery"
+    );
+    let levels = rorm::query(&db, Log.level).all().await.unwrap();
+    assert_eq!(levels, vec![1; 5]);
+
+    // 5 matching rows in batches of 5 and an empty one
+    let deleted = rorm::delete(&db, Log)
+        .delete_in_batches(Log.level.equals(1), NonZeroU64::new(5).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(deleted, 5);
+    assert!(rorm::query(&db, Log.id).all().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn query_raw_decodes_by_name() {
+    let db = setup(&[CREATE_USER, CREATE_COMMENT]).await;
+    rorm::insert(&db, User)
+        .return_nothing()
+        .single(&User {
+            id: 1,
+            name: "alice".to_string(),
+        })
+        .await
+        .unwrap();
+    rorm::insert(&db, Comment)
+        .return_nothing()
+        .bulk([1, 2].map(|id| Comment {
+            id,
+            user: Some(ForeignModelByField(1)),
+        }))
+        .await
+        .unwrap();
+
+    // The columns are selected in the opposite order than the selector's
+    let users = query_raw(&db, (User.id, User.name), "SELECT name, id FROM user;", &[])
+        .await
+        .unwrap();
+    assert_eq!(users, vec![(1, "alice".to_string())]);
+
+    // Aggregations are named after the aggregation and field
+    let counts = query_raw(
+        &db,
+        (Comment.user.name, Comment.id.count()),
+        "SELECT user.name, COUNT(comment.id) AS count_id FROM user JOIN comment ON comment.user = user.id GROUP BY user.id;",
+        &[],
+    )
+    .await
+    .unwrap();
+    assert_eq!(counts, vec![("alice".to_string(), 2)]);
+
+    // A missing column is an error instead of being decoded by position
+    let result = query_raw(&db, (User.id, User.name), "SELECT id FROM user;", &[]).await;
+    assert!(result.is_err());
+}
+
+#[derive(Model)]
+struct Host {
+    #[rorm(id)]
+    id: i64,
+
+    ip: IpAddr,
+
+    v6: Option<Ipv6Addr>,
+}
+
+#[derive(Patch)]
+#[rorm(model = "Host")]
+struct HostInsert {
+    ip: IpAddr,
+
+    v6: Option<Ipv6Addr>,
+}
+
+const CREATE_HOST: &str = "CREATE TABLE host (id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, ip VARCHAR(39) NOT NULL, v6 VARCHAR(39));";
+
+#[tokio::test]
+async fn ip_addr_round_trip() {
+    let db = setup(&[CREATE_HOST]).await;
+    let hosts = vec![
+        (IpAddr::from(Ipv4Addr::UNSPECIFIED), None),
+        (IpAddr::from(Ipv4Addr::BROADCAST), Some(Ipv6Addr::LOCALHOST)),
+        (
+            IpAddr::from(Ipv6Addr::from(u128::MAX)),
+            Some(Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0xc000, 0x0201)),
+        ),
+        (
+            "2001:0db8:0000:0000:0000:0000:0000:0001".parse().unwrap(),
+            Some(Ipv6Addr::UNSPECIFIED),
+        ),
+    ];
+    rorm::insert_many(&db, hosts.iter().map(|&(ip, v6)| HostInsert { ip, v6 }))
+        .await
+        .unwrap();
+
+    let stored = rorm::query(&db, (Host.ip, Host.v6))
+        .order_asc(Host.id)
+        .all()
+        .await
+        .unwrap();
+    assert_eq!(stored, hosts);
+
+    // The non-canonical input was stored canonically
+    let found = rorm::query(&db, Host.id)
+        .condition(Host.ip.equals("2001:db8::1".parse::<IpAddr>().unwrap()))
+        .all()
+        .await
+        .unwrap();
+    assert_eq!(found, vec![4]);
+}
+
+#[tokio::test]
+async fn first_and_last_order_by_the_field() {
+    let db = setup(&[CREATE_USER, CREATE_COMMENT]).await;
+    assert_eq!(
+        rorm::query(&db, Comment.id)
+            .first(Comment.id)
+            .await
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        rorm::query(&db, Comment.id).last(Comment.id).await.unwrap(),
+        None
+    );
+
+    rorm::insert(&db, User)
+        .return_nothing()
+        .bulk([
+            &User {
+                id: 1,
+                name: "alice".to_string(),
+            },
+            &User {
+                id: 2,
+                name: "bob".to_string(),
+            },
+        ])
+        .await
+        .unwrap();
+    rorm::insert(&db, Comment)
+        .return_nothing()
+        .bulk(
+            [
+                (3, Some(1)),
+                (1, Some(2)),
+                (4, Some(1)),
+                (2, None),
+                (5, Some(2)),
+            ]
+            .map(|(id, user)| Comment {
+                id,
+                user: user.map(ForeignModelByField),
+            }),
+        )
+        .await
+        .unwrap();
+
+    let first = rorm::query(&db, Comment.id)
+        .first(Comment.id)
+        .await
+        .unwrap();
+    assert_eq!(first, Some(1));
+    let last = rorm::query(&db, Comment.id).last(Comment.id).await.unwrap();
+    assert_eq!(last, Some(5));
+
+    // The condition joins the user table
+    let (statements, _guard) = record_sql();
+    let first = rorm::query(&db, Comment.id)
+        .condition(Comment.user.name.equals("alice"))
+        .first(Comment.id)
+        .await
+        .unwrap();
+    assert_eq!(first, Some(3));
+    let last = rorm::query(&db, Comment.id)
+        .condition(Comment.user.name.equals("alice"))
+        .last(Comment.id)
+        .await
+        .unwrap();
+    assert_eq!(last, Some(4));
+
+    // Both only fetch a single row ordered by the field
+    let statements = statements.lock().unwrap().clone();
+    assert_eq!(statements.len(), 2);
+    assert!(
+        statements[0].ends_with(" ORDER BY comment.id LIMIT 1;"),
+        "{}",
+        statements[0]
+    );
+    assert!(
+        statements[1].ends_with(" ORDER BY comment.id DESC LIMIT 1;"),
+        "{}",
+        statements[1]
+    );
+
+    let none = rorm::query(&db, Comment.id)
+        .condition(Comment.user.name.equals("eve"))
+        .first(Comment.id)
+        .await
+        .unwrap();
+    assert_eq!(none, None);
+}
+
+#[tokio::test]
+async fn values_selects_a_single_field() {
+    let db = setup(&[CREATE_LOG]).await;
+    rorm::insert_many(&db, [3, 1, 4, 1, 5].map(|level| LogInsert { level }))
+        .await
+        .unwrap();
+
+    let ids: Vec<i64> = rorm::query(&db, Log)
+        .order_asc(Log.id)
+        .values(Log.id)
+        .await
+        .unwrap();
+    assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+
+    let levels: Vec<i32> = rorm::query(&db, Log)
+        .condition(Log.level.greater_than(1))
+        .order_desc(Log.level)
+        .limit(2)
+        .values(Log.level)
+        .await
+        .unwrap();
+    assert_eq!(levels, vec![5, 4]);
+}
+
+#[tokio::test]
+async fn min_and_max_of_no_rows_are_none() {
+    let db = setup(&[CREATE_LOG]).await;
+    let (min, max) = rorm::query(&db, (Log.level.min(), Log.level.max()))
+        .one()
+        .await
+        .unwrap();
+    assert_eq!((min, max), (None, None));
+
+    rorm::insert_many(&db, [3, 1, 4].map(|level| LogInsert { level }))
+        .await
+        .unwrap();
+    let (min, max) = rorm::query(&db, (Log.level.min(), Log.level.max()))
+        .one()
+        .await
+        .unwrap();
+    assert_eq!((min, max), (Some(1), Some(4)));
+
+    // No row matches the condition
+    let (max,) = rorm::query(&db, (Log.level.max(),))
+        .condition(Log.level.greater_than(4))
+        .one()
+        .await
+        .unwrap();
+    assert_eq!(max, None);
+}
+
+#[tokio::test]
+async fn map_result_transforms_every_result() {
+    let db = setup(&[CREATE_LOG]).await;
+    rorm::insert_many(&db, [3, 1, 4].map(|level| LogInsert { level }))
+        .await
+        .unwrap();
+
+    let labels = rorm::query(&db, (Log.id, Log.level))
+        .order_asc(Log.id)
+        .map_result(|(id, level)| format!("{id}: {level}"))
+        .all()
+        .await
+        .unwrap();
+    assert_eq!(labels, ["1: 3", "2: 1", "3: 4"]);
+
+    let doubled = rorm::query(&db, Log.level)
+        .condition(Log.id.equals(3))
+        .map_result(|level| level * 2)
+        .one()
+        .await
+        .unwrap();
+    assert_eq!(doubled, 8);
+}
+
+#[tokio::test]
+async fn stream_ndjson_writes_one_line_per_result() {
+    let db = setup(&[CREATE_USER]).await;
+    rorm::insert(&db, User)
+        .return_nothing()
+        .bulk([
+            &User {
+                id: 1,
+                name: "alice".to_string(),
+            },
+            &User {
+                id: 2,
+                name: "bob".to_string(),
+            },
+        ])
+        .await
+        .unwrap();
+
+    let mut output = Vec::new();
+    let lines = rorm::query(&db, (User.id, User.name))
+        .order_asc(User.id)
+        .stream_ndjson(&mut output)
+        .await
+        .unwrap();
+    assert_eq!(lines, 2);
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "[1,\"alice\"]\n[2,\"bob\"]\n"
+    );
+
+    let mut output = Vec::new();
+    let lines = rorm::query(&db, User.id)
+        .condition(User.id.equals(3))
+        .stream_ndjson(&mut output)
+        .await
+        .unwrap();
+    assert_eq!(lines, 0);
+    assert!(output.is_empty());
+}
+
+#[tokio::test]
+async fn count_star_counts_rows_with_nulls() {
+    let db = setup(&[CREATE_USER, CREATE_COMMENT]).await;
+    let (rows, users) = rorm::query(&db, (Comment::count_star(), Comment.user.count()))
+        .one()
+        .await
+        .unwrap();
+    assert_eq!((rows, users), (0, 0));
+
+    rorm::insert(&db, User)
+        .return_nothing()
+        .single(&User {
+            id: 1,
+            name: "alice".to_string(),
+        })
+        .await
+        .unwrap();
+    rorm::insert(&db, Comment)
+        .return_nothing()
+        .bulk(
+            [(1, Some(1)), (2, None), (3, Some(1)), (4, None), (5, None)].map(|(id, user)| {
+                Comment {
+                    id,
+                    user: user.map(ForeignModelByField),
+                }
+            }),
+        )
+        .await
+        .unwrap();
+
+    // `count(*)` includes the comments without user, `count(user)` skips their NULLs
+    let (rows, users) = rorm::query(&db, (Comment::count_star(), Comment.user.count()))
+        .one()
+        .await
+        .unwrap();
+    assert_eq!((rows, users), (5, 2));
+}
+
+#[derive(Model)]
+struct Attachment {
+    #[rorm(id)]
+    id: i64,
+
+    data: bytes::Bytes,
+
+    thumbnail: Option<bytes::Bytes>,
+}
+
+#[derive(Patch)]
+#[rorm(model = "Attachment")]
+struct AttachmentInsert {
+    data: bytes::Bytes,
+    thumbnail: Option<bytes::Bytes>,
+}
+
+const CREATE_ATTACHMENT: &str = "CREATE TABLE attachment (id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, data BLOB NOT NULL, thumbnail BLOB);";
+
+#[tokio::test]
+async fn bytes_round_trip() {
+    let db = setup(&[CREATE_ATTACHMENT]).await;
+    let attachments = [
+        (bytes::Bytes::from_static(b"\x00\x01\x02"), None),
+        (
+            bytes::Bytes::from_static(b"\xff\xfe"),
+            Some(bytes::Bytes::from_static(b"thumb")),
+        ),
+    ];
+    for (data, thumbnail) in &attachments {
+        rorm::insert(&db, Attachment)
+            .return_nothing()
+            .single(&AttachmentInsert {
+                data: data.clone(),
+                thumbnail: thumbnail.clone(),
+            })
+            .await
+            .unwrap();
+    }
+
+    let stored = rorm::query(&db, (Attachment.data, Attachment.thumbnail))
+        .order_asc(Attachment.id)
+        .all()
+        .await
+        .unwrap();
+    assert_eq!(stored, attachments);
+
+    let (id,) = rorm::query(&db, (Attachment.id,))
+        .condition(Attachment.data.equals(&attachments[1].0))
+        .one()
+        .await
+        .unwrap();
+    assert_eq!(id, 2);
+
+    let (id,) = rorm::query(&db, (Attachment.id,))
+        .condition(Attachment.thumbnail.equals(Some(b"thumb".as_slice())))
+        .one()
+        .await
+        .unwrap();
+    assert_eq!(id, 2);
+
+    // Like in sql, `NULL` never equals anything, use `is_none` instead
+    let found = rorm::query(&db, (Attachment.id,))
+        .condition(Attachment.thumbnail.equals(None::<bytes::Bytes>))
+        .all()
+        .await
+        .unwrap();
+    assert!(found.is_empty());
+    let (id,) = rorm::query(&db, (Attachment.id,))
+        .condition(Attachment.thumbnail.is_none())
+        .one()
+        .await
+        .unwrap();
+    assert_eq!(id, 1);
+}
+
+#[derive(Model)]
+struct Thread {
+    #[rorm(id)]
+    id: i64,
+
+    posts: BackRef<field!(Post.thread)>,
+}
+
+#[derive(Model)]
+struct Post {
+    #[rorm(id)]
+    id: i64,
+
+    thread: ForeignModel<Thread>,
+}
+
+#[tokio::test]
+async fn back_ref_load_queries_the_referencing_models() {
+    let db = setup(&[
+        "CREATE TABLE thread (id INTEGER PRIMARY KEY NOT NULL);",
+        "CREATE TABLE post (id INTEGER PRIMARY KEY NOT NULL, thread INTEGER NOT NULL REFERENCES thread (id));",
+        "INSERT INTO thread (id) VALUES (1), (2), (3);",
+        "INSERT INTO post (id, thread) VALUES (10, 1), (11, 2), (12, 1);",
+    ])
+    .await;
+    let mut threads = rorm::query(&db, Thread)
+        .order_asc(Thread.id)
+        .all()
+        .await
+        .unwrap();
+    assert!(threads.iter().all(|thread| thread.posts.cached.is_none()));
+
+    let futures: Vec<_> = threads
+        .iter()
+        .map(|thread| Thread.posts.load(&db, thread))
+        .collect();
+    // The futures don't borrow the threads, so they can store the results in their caches
+    for (thread, posts) in threads.iter_mut().zip(futures) {
+        thread.posts.cached = Some(posts.await.unwrap());
+    }
+
+    let loaded: Vec<_> = threads
+        .iter()
+        .map(|thread| {
+            let posts = thread.posts.get().unwrap();
+            assert!(posts.iter().all(|post| post.thread.0 == thread.id));
+            let mut ids: Vec<_> = posts.iter().map(|post| post.id).collect();
+            ids.sort();
+            (thread.id, ids)
+        })
+        .collect();
+    assert_eq!(loaded, [(1, vec![10, 12]), (2, vec![11]), (3, vec![])]);
+}
@@ -4,7 +4,7 @@ use syn::visit_mut::VisitMut;
 use syn::{Generics, LitInt, LitStr, Type, Visibility};
 
 use crate::analyze::vis_to_display;
-use crate::parse::annotations::{Default, Index, OnAction};
+use crate::parse::annotations::{Default, Index, OnAction, Serde};
 use crate::parse::model::{ModelAnnotations, ModelFieldAnnotations, ParsedField, ParsedModel};
 use crate::utils::to_db_name;
 
@@ -16,6 +16,9 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
         annos:
             ModelAnnotations {
                 rename,
+                serde,
+                utoipa,
+                schemars,
                 experimental_unregistered,
                 experimental_generics,
             },
@@ -31,6 +34,21 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
     if generics.lt_token.is_some() && !experimental_generics {
         errors.push(darling::Error::custom("Generic models are not supported yet. You can try the `experimental_generics` attribute"));
     }
+    if generics.lt_token.is_some() && serde.is_some() {
+        errors.push(darling::Error::custom(
+            "`serde` is not supported on generic models",
+        ));
+    }
+    if generics.lt_token.is_some() && utoipa {
+        errors.push(darling::Error::custom(
+            "`utoipa` is not supported on generic models",
+        ));
+    }
+    if generics.lt_token.is_some() && schemars {
+        errors.push(darling::Error::custom(
+            "`schemars` is not supported on generic models",
+        ));
+    }
 
     // Get table name
     let table = rename.unwrap_or_else(|| LitStr::new(&to_db_name(ident.to_string()), ident.span()));
@@ -57,6 +75,8 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
                     mut primary_key,
                     unique,
                     id,
+                    nullable,
+                    not_null,
                     on_delete,
                     on_update,
                     rename,
@@ -64,6 +84,7 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
                     default,
                     max_length,
                     index,
+                    foreign,
                 },
         } = field;
         // Get column name
@@ -95,6 +116,22 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
             auto_increment = true;
         }
 
+        // Handle #[rorm(nullable)] and #[rorm(not_null)] annotations
+        let explicit_nullable = match (nullable, not_null) {
+            (false, false) => None,
+            (true, false) => Some(true),
+            (false, true) => Some(false),
+            (true, true) => {
+                errors.push(
+                    darling::Error::custom(
+                        "`#[rorm(nullable)]` and `#[rorm(not_null)]` contradict each other. Please remove one of them.",
+                    )
+                        .with_span(&ident),
+                );
+                None
+            }
+        };
+
         // Replace `Self` in the field's type to the model's identifier
         struct ReplaceSelf<'a>(&'a Ident);
         impl VisitMut for ReplaceSelf<'_> {
@@ -123,6 +160,10 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
                 default,
                 max_length,
                 index,
+                explicit_nullable,
+                no_constraint: foreign
+                    .map(|foreign| foreign.no_constraint)
+                    .unwrap_or(false),
             },
         });
     }
@@ -160,6 +201,9 @@ pub fn analyze_model(parsed: ParsedModel) -> darling::Result<AnalyzedModel> {
         table,
         fields: analyzed_fields,
         primary_key,
+        serde,
+        utoipa,
+        schemars,
         experimental_unregistered,
         experimental_generics: generics,
     })
@@ -172,6 +216,9 @@ pub struct AnalyzedModel {
     pub fields: Vec<AnalyzedField>,
     /// the primary key's index
     pub primary_key: usize,
+    pub serde: Option<Serde>,
+    pub utoipa: bool,
+    pub schemars: bool,
 
     pub experimental_unregistered: bool,
     pub experimental_generics: Generics,
@@ -197,4 +244,6 @@ pub struct AnalyzedModelFieldAnnotations {
     pub default: Option<Default>,
     pub max_length: Option<LitInt>,
     pub index: Option<Index>,
+    pub explicit_nullable: Option<bool>,
+    pub no_constraint: bool,
 }
@@ -1,4 +1,7 @@
 pub mod db_enum;
 pub mod model;
 pub mod patch;
+mod schemars;
+mod serde;
 mod utils;
+mod utoipa;
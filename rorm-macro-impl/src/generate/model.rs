@@ -4,8 +4,11 @@ use syn::{GenericParam, LitStr};
 
 use crate::analyze::model::{AnalyzedField, AnalyzedModel, AnalyzedModelFieldAnnotations};
 use crate::generate::patch::partially_generate_patch;
+use crate::generate::schemars::generate_json_schema;
+use crate::generate::serde::generate_serde;
 use crate::generate::utils::get_source;
 use crate::generate::utils::phantom_data;
+use crate::generate::utoipa::generate_to_schema;
 use crate::parse::annotations::{Index, NamedIndex, OnAction};
 
 pub fn generate_model(model: &AnalyzedModel) -> TokenStream {
@@ -18,6 +21,9 @@ pub fn generate_model(model: &AnalyzedModel) -> TokenStream {
         table,
         fields,
         primary_key,
+        serde,
+        utoipa,
+        schemars,
         experimental_unregistered,
         experimental_generics,
     } = model;
@@ -55,6 +61,15 @@ pub fn generate_model(model: &AnalyzedModel) -> TokenStream {
         }
     };
 
+    // Registered models can't be generic, so a static is unique to each model
+    let get_imr = (!*experimental_unregistered).then(|| {
+        quote! {
+            fn get_imr() -> ::rorm::imr::Model {
+                <Self as ::rorm::model::CachedImr>::cached_imr().clone()
+            }
+        }
+    });
+
     let mut tokens = quote! {
         #field_declarations
         #fields_struct
@@ -79,12 +94,44 @@ pub fn generate_model(model: &AnalyzedModel) -> TokenStream {
             fn push_fields_imr(fields: &mut Vec<::rorm::imr::Field>) {#(
                 ::rorm::internal::field::push_imr::<#field_structs_1 #type_generics>(&mut *fields);
             )*}
+
+            #get_imr
         }
 
         #impl_patch
     };
+    if let Some(serde) = serde {
+        tokens.extend(generate_serde(
+            ident,
+            fields.iter().map(|field| {
+                let rename = serde.columns.then_some(&field.column);
+                (&field.ident, &field.ty, rename)
+            }),
+        ));
+    }
+    // The schemas' property names should match the ones used by serde
+    let schema_fields = fields.iter().map(|field| {
+        let name = match serde {
+            Some(serde) if serde.columns => field.column.value(),
+            _ => field.ident.to_string(),
+        };
+        (field.unit.to_token_stream(), name)
+    });
+    if *utoipa {
+        tokens.extend(generate_to_schema(ident, schema_fields.clone()));
+    }
+    if *schemars {
+        tokens.extend(generate_json_schema(ident, schema_fields));
+    }
     if !*experimental_unregistered {
         tokens.extend(quote! {
+            impl ::rorm::model::CachedImr for #ident {
+                fn cached_imr() -> &'static ::rorm::imr::Model {
+                    static IMR: ::std::sync::OnceLock<::rorm::imr::Model> = ::std::sync::OnceLock::new();
+                    IMR.get_or_init(::rorm::model::build_imr::<Self>)
+                }
+            }
+
             const _: () = {
                 #[::rorm::linkme::distributed_slice(::rorm::MODELS)]
                 #[linkme(crate = ::rorm::linkme)]
@@ -213,6 +260,8 @@ fn generate_field_annotations(annos: &AnalyzedModelFieldAnnotations) -> TokenStr
         default,
         max_length,
         index,
+        explicit_nullable,
+        no_constraint,
     } = annos;
 
     // Convert every field into its "creation" expression
@@ -221,6 +270,7 @@ fn generate_field_annotations(annos: &AnalyzedModelFieldAnnotations) -> TokenStr
     let auto_increment = auto_increment.then(|| quote! {AutoIncrement});
     let primary_key = primary_key.then(|| quote! {PrimaryKey});
     let unique = unique.then(|| quote! {Unique});
+    let no_constraint = no_constraint.then(|| quote! {NoConstraint});
     let max_length = max_length.as_ref().map(|len| quote! {MaxLength(#len)});
     let default = default.as_ref().map(|default| {
         let variant = Ident::new(default.variant, default.literal.span());
@@ -270,10 +320,15 @@ fn generate_field_annotations(annos: &AnalyzedModelFieldAnnotations) -> TokenStr
     let default = finalize(default);
     let index = finalize(index);
     let max_length = finalize(max_length);
+    let no_constraint = finalize(no_constraint);
     let on_delete = finalize(on_delete);
     let on_update = finalize(on_update);
     let primary_key = finalize(primary_key);
     let unique = finalize(unique);
+    let explicit_nullable = match explicit_nullable {
+        Some(nullable) => quote! {Some(#nullable)},
+        None => quote! {None},
+    };
 
     quote! {
         ::rorm::internal::hmr::annotations::Annotations {
@@ -284,10 +339,12 @@ fn generate_field_annotations(annos: &AnalyzedModelFieldAnnotations) -> TokenStr
             default: #default,
             index: #index,
             max_length: #max_length,
+            no_constraint: #no_constraint,
             on_delete: #on_delete,
             on_update: #on_update,
             primary_key: #primary_key,
             unique: #unique,
+            explicit_nullable: #explicit_nullable,
             nullable: false, // Set implicitly by type
             foreign: None,   //
         }
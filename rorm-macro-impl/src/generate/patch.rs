@@ -4,6 +4,9 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 use syn::{Generics, Type, Visibility};
 
+use crate::generate::schemars::generate_json_schema;
+use crate::generate::serde::generate_serde;
+use crate::generate::utoipa::generate_to_schema;
 use crate::parse::patch::ParsedPatch;
 
 pub fn generate_patch(patch: &ParsedPatch) -> TokenStream {
@@ -11,6 +14,9 @@ pub fn generate_patch(patch: &ParsedPatch) -> TokenStream {
         vis,
         ident,
         model,
+        serde,
+        utoipa,
+        schemars,
         fields,
     } = patch;
 
@@ -27,9 +33,32 @@ pub fn generate_patch(patch: &ParsedPatch) -> TokenStream {
         fields.iter().map(|field| &field.ty),
     );
 
+    let serde = serde.then(|| {
+        generate_serde(
+            ident,
+            fields.iter().map(|field| (&field.ident, &field.ty, None)),
+        )
+    });
+
+    let schema_fields = fields.iter().map(|field| {
+        let field_ident = &field.ident;
+        (
+            quote! { ::rorm::get_field!(#ident, #field_ident) },
+            field_ident.to_string(),
+        )
+    });
+    let utoipa = utoipa.then(|| generate_to_schema(ident, schema_fields.clone()));
+    let schemars = schemars.then(|| generate_json_schema(ident, schema_fields));
+
     quote! {
         #partial
 
+        #serde
+
+        #utoipa
+
+        #schemars
+
         #(
             impl ::rorm::model::GetField<::rorm::get_field!(#ident, #field_idents_2)> for #ident {
                 fn get_field(self) -> #field_types {
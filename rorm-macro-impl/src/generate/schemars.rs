@@ -0,0 +1,31 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Generates a `JsonSchema` impl for a model or patch
+///
+/// Each field is described by its field type (implementing `Field`) and the name to use in the schema.
+pub fn generate_json_schema(
+    ident: &Ident,
+    fields: impl Iterator<Item = (TokenStream, String)>,
+) -> TokenStream {
+    let name = ident.to_string();
+    let (field_types, field_names): (Vec<_>, Vec<_>) = fields.unzip();
+
+    quote! {
+        impl ::rorm::internal::json_schema::JsonSchema for #ident {
+            fn schema_name() -> String {
+                #name.to_string()
+            }
+
+            fn json_schema(
+                gen: &mut ::rorm::internal::json_schema::SchemaGenerator,
+            ) -> ::rorm::internal::json_schema::Schema {
+                let mut object = ::rorm::internal::json_schema::new_object();
+                #(
+                    ::rorm::internal::json_schema::add_field::<#field_types>(&mut *gen, &mut object, #field_names);
+                )*
+                ::rorm::internal::json_schema::Schema::Object(object)
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{LitStr, Type};
+
+/// Generates `Serialize` and `Deserialize` impls for a model or patch
+///
+/// The impls are delegated to private structs mirroring the input
+/// on which serde's own derive macros do the actual work.
+///
+/// Each field is described by its identifier, its type and an optional name to rename it to.
+pub fn generate_serde<'a>(
+    ident: &Ident,
+    fields: impl Iterator<Item = (&'a Ident, &'a Type, Option<&'a LitStr>)> + Clone,
+) -> TokenStream {
+    let name = ident.to_string();
+    let field_idents_1 = fields.clone().map(|(ident, _, _)| ident);
+    let field_idents_2 = field_idents_1.clone();
+    let field_idents_3 = field_idents_1.clone();
+    let field_idents_4 = field_idents_1.clone();
+    let field_idents_5 = field_idents_1.clone();
+    let field_types_1 = fields.clone().map(|(_, ty, _)| ty);
+    let field_types_2 = field_types_1.clone();
+    let renames_1 =
+        fields.map(|(_, _, rename)| rename.map(|rename| quote! { #[serde(rename = #rename)] }));
+    let renames_2 = renames_1.clone();
+
+    quote! {
+        const _: () = {
+            #[derive(::rorm::serde::Serialize)]
+            #[serde(crate = "::rorm::serde", rename = #name)]
+            struct __Serialize<'a> {#(
+                #renames_1
+                #field_idents_1: &'a #field_types_1,
+            )*}
+            impl ::rorm::serde::Serialize for #ident {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: ::rorm::serde::Serializer,
+                {
+                    ::rorm::serde::Serialize::serialize(
+                        &__Serialize {#(
+                            #field_idents_2: &self.#field_idents_2,
+                        )*},
+                        serializer,
+                    )
+                }
+            }
+
+            #[derive(::rorm::serde::Deserialize)]
+            #[serde(crate = "::rorm::serde", rename = #name)]
+            struct __Deserialize {#(
+                #renames_2
+                #field_idents_3: #field_types_2,
+            )*}
+            impl<'de> ::rorm::serde::Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: ::rorm::serde::Deserializer<'de>,
+                {
+                    let __Deserialize {#(
+                        #field_idents_4,
+                    )*} = ::rorm::serde::Deserialize::deserialize(deserializer)?;
+                    Ok(Self {#(
+                        #field_idents_5,
+                    )*})
+                }
+            }
+        };
+    }
+}
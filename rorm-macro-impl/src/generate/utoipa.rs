@@ -0,0 +1,28 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Generates a `ToSchema` impl for a model or patch
+///
+/// Each field is described by its field type (implementing `Field`) and the name to use in the schema.
+pub fn generate_to_schema(
+    ident: &Ident,
+    fields: impl Iterator<Item = (TokenStream, String)>,
+) -> TokenStream {
+    let name = ident.to_string();
+    let (field_types, field_names): (Vec<_>, Vec<_>) = fields.unzip();
+
+    quote! {
+        impl<'s> ::rorm::internal::to_schema::ToSchema<'s> for #ident {
+            fn schema() -> (
+                &'s str,
+                ::rorm::internal::to_schema::RefOr<::rorm::internal::to_schema::Schema>,
+            ) {
+                let object = ::rorm::internal::to_schema::new_object();
+                #(
+                    let object = ::rorm::internal::to_schema::add_field::<#field_types>(object, #field_names);
+                )*
+                (#name, ::rorm::internal::to_schema::build_object(object))
+            }
+        }
+    }
+}
@@ -14,16 +14,23 @@ pub struct Default {
 }
 impl FromMeta for Default {
     fn from_value(value: &Lit) -> darling::Result<Self> {
-        Ok(Default {
-            variant: match value {
-                Lit::Str(_) => Ok("String"),
-                Lit::Int(_) => Ok("Integer"),
-                Lit::Float(_) => Ok("Float"),
-                Lit::Bool(_) => Ok("Boolean"),
-                _ => Err(Error::unexpected_lit_type(value)),
-            }?,
-            literal: value.clone(),
-        })
+        let (variant, literal) = match value {
+            Lit::Str(_) => ("String", value.clone()),
+            Lit::Int(_) => ("Integer", value.clone()),
+            Lit::Float(_) => ("Float", value.clone()),
+            Lit::Bool(_) => ("Boolean", value.clone()),
+            // Binary data is represented using hexadecimal
+            Lit::ByteStr(bytes) => {
+                let hex: String = bytes
+                    .value()
+                    .iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect();
+                ("Binary", Lit::Str(LitStr::new(&hex, bytes.span())))
+            }
+            _ => return Err(Error::unexpected_lit_type(value)),
+        };
+        Ok(Default { variant, literal })
     }
 }
 
@@ -69,3 +76,27 @@ pub struct NamedIndex {
     pub name: LitStr,
     pub priority: Option<LitInt>,
 }
+
+#[derive(FromMeta, Default, Debug)]
+pub struct Foreign {
+    pub no_constraint: bool,
+}
+
+#[derive(Default, Debug)]
+pub struct Serde {
+    pub columns: bool,
+}
+impl FromMeta for Serde {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Serde::default())
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        struct SerdeOptions {
+            columns: bool,
+        }
+        let SerdeOptions { columns } = SerdeOptions::from_list(items)?;
+        Ok(Serde { columns })
+    }
+}
@@ -2,7 +2,7 @@ use darling::FromAttributes;
 use proc_macro2::{Ident, TokenStream};
 use syn::{parse2, Field, Generics, ItemStruct, LitInt, LitStr, Type, Visibility};
 
-use crate::parse::annotations::{Default, Index, OnAction};
+use crate::parse::annotations::{Default, Foreign, Index, OnAction, Serde};
 use crate::parse::get_fields_named;
 
 pub fn parse_model(tokens: TokenStream) -> darling::Result<ParsedModel> {
@@ -70,6 +70,20 @@ pub struct ParsedModel {
 pub struct ModelAnnotations {
     pub rename: Option<LitStr>,
 
+    /// Parse the `#[rorm(serde)]` annotation.
+    ///
+    /// It accepts two different syntax's:
+    /// - `#[rorm(serde)]`
+    /// - `#[rorm(serde(columns))]`
+    ///   *(uses the column names instead of the field names)*
+    pub serde: Option<Serde>,
+
+    /// Parse the `#[rorm(utoipa)]` annotation.
+    pub utoipa: bool,
+
+    /// Parse the `#[rorm(schemars)]` annotation.
+    pub schemars: bool,
+
     pub experimental_unregistered: bool,
     pub experimental_generics: bool,
 }
@@ -102,6 +116,12 @@ pub struct ModelFieldAnnotations {
     /// `#[rorm(id)]`
     pub id: bool,
 
+    /// `#[rorm(nullable)]`
+    pub nullable: bool,
+
+    /// `#[rorm(not_null)]`
+    pub not_null: bool,
+
     /// `#[rorm(on_delete = "..")]`
     pub on_delete: Option<OnAction>,
 
@@ -122,6 +142,7 @@ pub struct ModelFieldAnnotations {
     /// - Integer
     /// - Floating Point Number
     /// - Boolean
+    /// - Byte String (converted to a hexadecimal string)
     ///
     /// TODO: Figure out how to check the literal's type is compatible with the annotated field's type
     pub default: Option<Default>,
@@ -141,4 +162,10 @@ pub struct ModelFieldAnnotations {
     /// - `#[rorm(index(name = <string literal>, priority = <integer literal>))]`
     ///    *(insensitive to argument order)*
     pub index: Option<Index>,
+
+    /// Parse the `#[rorm(foreign(..))]` annotation.
+    ///
+    /// It accepts a list of options for the field's foreign key:
+    /// - `#[rorm(foreign(no_constraint))]`
+    pub foreign: Option<Foreign>,
 }
@@ -20,13 +20,15 @@ pub fn parse_patch(tokens: TokenStream) -> darling::Result<ParsedPatch> {
 
     // Parse annotations
     let annos = errors.handle(PatchAnnotations::from_attributes(&attrs));
-    let model = annos.map(|annos| annos.model).unwrap_or_else(|| {
-        PathSegment {
-            ident: format_ident!(""),
-            arguments: Default::default(),
-        }
-        .into()
-    });
+    let (model, serde, utoipa, schemars) = annos
+        .map(|annos| (annos.model, annos.serde, annos.utoipa, annos.schemars))
+        .unwrap_or_else(|| {
+            let model = PathSegment {
+                ident: format_ident!(""),
+                arguments: Default::default(),
+            };
+            (model.into(), false, false, false)
+        });
 
     // Check absence of generics
     errors.handle(check_non_generic(generics));
@@ -57,6 +59,9 @@ pub fn parse_patch(tokens: TokenStream) -> darling::Result<ParsedPatch> {
         vis,
         ident,
         model,
+        serde,
+        utoipa,
+        schemars,
         fields: parsed_fields,
     })
 }
@@ -65,6 +70,9 @@ pub struct ParsedPatch {
     pub vis: Visibility,
     pub ident: Ident,
     pub model: Path,
+    pub serde: bool,
+    pub utoipa: bool,
+    pub schemars: bool,
     pub fields: Vec<ParsedPatchField>,
 }
 
@@ -77,4 +85,16 @@ pub struct ParsedPatchField {
 #[darling(attributes(rorm))]
 pub struct PatchAnnotations {
     pub model: Path,
+
+    /// `#[rorm(serde)]`
+    #[darling(default)]
+    pub serde: bool,
+
+    /// `#[rorm(utoipa)]`
+    #[darling(default)]
+    pub utoipa: bool,
+
+    /// `#[rorm(schemars)]`
+    #[darling(default)]
+    pub schemars: bool,
 }
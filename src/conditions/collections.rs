@@ -49,9 +49,10 @@
 //! }
 //! ```
 
-use super::Condition;
+use super::{Condition, ConditionOrigin};
 use crate::internal::query_context::flat_conditions::FlatCondition;
 use crate::internal::query_context::QueryContext;
+use crate::model::Model;
 
 /// Operator to join a collection's conditions with
 #[derive(Copy, Clone, Debug)]
@@ -127,6 +128,7 @@ impl<'a, T: Condition<'a>> Condition<'a> for DynamicCollection<Option<T>> {
         context.conditions.push(FlatCondition::EndCollection);
     }
 }
+impl<M: Model, T: ConditionOrigin<M>> ConditionOrigin<M> for DynamicCollection<T> {}
 
 /// A collection of conditions with static size.
 ///
@@ -206,6 +208,8 @@ macro_rules! impl_static_collection {
                 context.conditions.push(FlatCondition::EndCollection);
             }
         }
+
+        impl<M: Model, $($generic: ConditionOrigin<M>),+> ConditionOrigin<M> for StaticCollection<($($generic,)+)> {}
     };
     ($($generic:ident),+) => {
         impl_static_collection!(recu $($generic),+);
@@ -262,6 +266,100 @@ macro_rules! and {
     };
 }
 
+/// Build the condition selecting all rows after a given one (a "keyset") in a multi column ordering.
+///
+/// Each argument is either `asc(field, value)` or `desc(field, value)`,
+/// matching the query's `order_by_asc` and `order_by_desc` calls in the same order.
+/// The values are the last seen row's values for these fields.
+///
+/// Since not every database supports comparing row values (i.e. `(a, b) > (?, ?)`),
+/// the comparison is expanded into `a > ? OR (a = ? AND (b > ? OR ...))`.
+/// Each value except the last one is used twice, so they should be references or `Copy`.
+///
+/// ```
+/// # use rorm::conditions::{Binary, BinaryOperator};
+/// # use rorm::conditions::collections::{CollectionOperator, StaticCollection};
+/// # use rorm::keyset;
+/// # use rorm::prelude::*;
+/// # #[derive(Model)]
+/// # struct Post {
+/// #     #[rorm(id)]
+/// #     id: i64,
+/// #     created_at: i64,
+/// #     score: i32,
+/// # }
+/// // created_at > 1337 OR (created_at = 1337 AND id < 42)
+/// let StaticCollection {
+///     operator: CollectionOperator::Or,
+///     tuple: (
+///         Binary { operator: BinaryOperator::Greater, .. },
+///         StaticCollection {
+///             operator: CollectionOperator::And,
+///             tuple: (
+///                 Binary { operator: BinaryOperator::Equals, .. },
+///                 Binary { operator: BinaryOperator::Less, .. },
+///             ),
+///         },
+///     ),
+/// } = keyset!(asc(Post.created_at, 1337), desc(Post.id, 42))
+/// else {
+///     unreachable!();
+/// };
+///
+/// // score < 3 OR (score = 3 AND (created_at > 1337 OR (created_at = 1337 AND id > 42)))
+/// let condition = keyset!(
+///     desc(Post.score, 3),
+///     asc(Post.created_at, 1337),
+///     asc(Post.id, 42),
+/// );
+/// # let StaticCollection {
+/// #     operator: CollectionOperator::Or,
+/// #     tuple: (
+/// #         Binary { operator: BinaryOperator::Less, .. },
+/// #         StaticCollection {
+/// #             operator: CollectionOperator::And,
+/// #             tuple: (
+/// #                 Binary { operator: BinaryOperator::Equals, .. },
+/// #                 StaticCollection {
+/// #                     operator: CollectionOperator::Or,
+/// #                     tuple: (
+/// #                         Binary { operator: BinaryOperator::Greater, .. },
+/// #                         StaticCollection {
+/// #                             operator: CollectionOperator::And,
+/// #                             tuple: (
+/// #                                 Binary { operator: BinaryOperator::Equals, .. },
+/// #                                 Binary { operator: BinaryOperator::Greater, .. },
+/// #                             ),
+/// #                         },
+/// #                     ),
+/// #                 },
+/// #             ),
+/// #         },
+/// #     ),
+/// # } = condition
+/// # else {
+/// #     unreachable!();
+/// # };
+/// ```
+#[macro_export]
+macro_rules! keyset {
+    (@cmp asc, $field:expr, $value:expr) => {
+        $field.greater_than($value)
+    };
+    (@cmp desc, $field:expr, $value:expr) => {
+        $field.less_than($value)
+    };
+    ($direction:ident($field:expr, $value:expr) $(,)?) => {
+        $crate::keyset!(@cmp $direction, $field, $value)
+    };
+    ($direction:ident($field:expr, $value:expr), $($tail:tt)+) => {
+        $crate::or!(
+            $crate::keyset!(@cmp $direction, $field, $value),
+            $crate::and!($field.equals($value), $crate::keyset!($($tail)+)),
+        )
+    };
+}
+
 #[doc(hidden)]
 pub trait IsCondition {}
 impl<'c, C: Condition<'c>> IsCondition for C {}
@@ -1,7 +1,8 @@
 use crate::conditions::collections::CollectionOperator;
-use crate::conditions::{BinaryOperator, Condition, Value};
+use crate::conditions::{BinaryOperator, Condition, ConditionOrigin, Value};
 use crate::internal::query_context::flat_conditions::FlatCondition;
 use crate::internal::query_context::QueryContext;
+use crate::model::Model;
 
 /// An "IN" expression
 ///
@@ -49,3 +50,4 @@ where
         }
     }
 }
+impl<M: Model, A: ConditionOrigin<M>, B: ConditionOrigin<M>> ConditionOrigin<M> for In<A, B> {}
@@ -3,10 +3,13 @@
 //! It is basically a generic version of the [`rorm_db::Condition`](conditional::Condition) tree.
 
 use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+use std::mem;
 use std::sync::Arc;
 
 // use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use rorm_db::sql::value;
+use rorm_db::Error;
 
 pub mod collections;
 mod r#in;
@@ -19,6 +22,7 @@ use crate::internal::field::Field;
 use crate::internal::query_context::flat_conditions::FlatCondition;
 use crate::internal::query_context::QueryContext;
 use crate::internal::relation_path::Path;
+use crate::model::Model;
 
 /// Node in a condition tree
 pub trait Condition<'a>: Send + Sync {
@@ -48,6 +52,38 @@ pub trait Condition<'a>: Send + Sync {
     }
 }
 
+/// Marker for conditions which only use fields reachable from the model `M`
+///
+/// Query builders require it for the conditions passed to them,
+/// so a field of a model which is not part of the query is rejected at compile time.
+///
+/// Type erased conditions (`Box<dyn Condition>` and `Arc<dyn Condition>`) implement it for every model.
+/// They are not checked at all, so using the wrong model in them results in an error from the database.
+///
+/// If you implement `Condition` for a custom type,
+/// implement this marker for the models your condition may be used with, too.
+///
+/// ```compile_fail
+/// # use rorm::conditions::ConditionOrigin;
+/// # use rorm::internal::field::Field;
+/// # use rorm::prelude::*;
+/// # #[derive(Model)]
+/// # struct User {
+/// #     #[rorm(id)]
+/// #     id: i64,
+/// # }
+/// # #[derive(Model)]
+/// # struct Post {
+/// #     #[rorm(id)]
+/// #     id: i64,
+/// # }
+/// fn assert_origin<M: Model>(_: impl ConditionOrigin<M>) {}
+///
+/// // A condition on `Post` can't be used in a query on `User`
+/// assert_origin::<User>(Post.id.equals(1));
+/// ```
+pub trait ConditionOrigin<M: Model> {}
+
 impl<'a> Condition<'a> for Box<dyn Condition<'a> + '_> {
     fn build(&self, context: &mut QueryContext<'a>) {
         self.as_ref().build(context);
@@ -91,6 +127,11 @@ impl<'a, C: Condition<'a> + ?Sized> Condition<'a> for &'_ C {
         <C as Condition<'a>>::build(*self, context);
     }
 }
+impl<M: Model> ConditionOrigin<M> for Box<dyn Condition<'_> + '_> {}
+impl<M: Model> ConditionOrigin<M> for Arc<dyn Condition<'_> + '_> {}
+impl<M: Model, C: ConditionOrigin<M> + ?Sized> ConditionOrigin<M> for &'_ C {}
+/// Optional conditions in [collections] whose `None`s are skipped
+impl<M: Model, C: ConditionOrigin<M>> ConditionOrigin<M> for Option<C> {}
 
 /// A value
 ///
@@ -153,11 +194,33 @@ pub enum Value<'a> {
     /// Bit vec representation
     #[cfg(feature = "postgres-only")]
     BitVec(crate::fields::types::postgres_only::BitCow<'a>),
+    /// A value which couldn't be converted into any of the other variants
+    ///
+    /// For example a [`PathBuf`](std::path::PathBuf) which isn't valid UTF-8.
+    /// It is never sent to the database, executing a query using it fails with the contained message instead.
+    Invalid(Cow<'a, str>),
 }
 impl Value<'_> {
     /// Convert into an [`sql::Value`](value::Value) instead of an [`sql::Condition`](conditional::Condition) directly.
+    ///
+    /// # Panics
+    /// If the value is [`Value::Invalid`], use [`Value::try_as_sql`] to get an error instead.
     pub fn as_sql(&self) -> value::Value {
-        match self {
+        self.try_as_sql().unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Convert into an [`sql::Value`](value::Value) failing for [`Value::Invalid`]
+    ///
+    /// ```
+    /// # use std::borrow::Cow;
+    /// # use rorm::conditions::Value;
+    /// assert!(Value::I64(1).try_as_sql().is_ok());
+    ///
+    /// let error = Value::Invalid(Cow::Borrowed("not a value")).try_as_sql().unwrap_err();
+    /// assert!(error.to_string().contains("not a value"));
+    /// ```
+    pub fn try_as_sql(&self) -> Result<value::Value<'_>, Error> {
+        Ok(match self {
             Value::Null(null_type) => value::Value::Null(*null_type),
             Value::String(v) => value::Value::String(v.as_ref()),
             Value::Choice(v) => value::Value::Choice(v.as_ref()),
@@ -192,6 +255,120 @@ impl Value<'_> {
             Value::IpNetwork(v) => value::Value::IpNetwork(*v),
             #[cfg(feature = "postgres-only")]
             Value::BitVec(v) => value::Value::BitVec(v.as_ref()),
+            Value::Invalid(message) => {
+                return Err(Error::SQLBuildError(
+                    rorm_db::sql::error::Error::SQLBuildError(message.to_string()),
+                ))
+            }
+        })
+    }
+}
+
+/// Compares two values of the same variant by their content
+///
+/// Floats are compared by their bits, so that `Value` can implement [`Eq`] and [`Hash`].
+/// This means `NaN` equals itself (if it has the same bits) and `0.0` doesn't equal `-0.0`.
+///
+/// Borrowed and owned values are equal if their content is.
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use rorm::conditions::Value;
+/// # use rorm::db::sql::value::NullType;
+/// assert_eq!(Value::I64(1), Value::I64(1));
+/// assert_ne!(Value::I64(1), Value::I32(1));
+/// assert_eq!(
+///     Value::String(Cow::Borrowed("foo")),
+///     Value::String(Cow::Owned("foo".to_string()))
+/// );
+/// assert_ne!(Value::String(Cow::Borrowed("foo")), Value::Choice(Cow::Borrowed("foo")));
+/// assert_eq!(Value::F64(f64::NAN), Value::F64(f64::NAN));
+/// assert_ne!(Value::F64(0.0), Value::F64(-0.0));
+/// assert_eq!(Value::Null(NullType::String), Value::Null(NullType::String));
+/// assert_ne!(Value::Null(NullType::String), Value::Null(NullType::I64));
+/// ```
+impl PartialEq for Value<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Null(a), Value::Null(b)) => mem::discriminant(a) == mem::discriminant(b),
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::Choice(a), Value::Choice(b)) => a == b,
+            (Value::I64(a), Value::I64(b)) => a == b,
+            (Value::I32(a), Value::I32(b)) => a == b,
+            (Value::I16(a), Value::I16(b)) => a == b,
+            (Value::Bool(a), Value::Bool(b)) => a == b,
+            (Value::F64(a), Value::F64(b)) => a.to_bits() == b.to_bits(),
+            (Value::F32(a), Value::F32(b)) => a.to_bits() == b.to_bits(),
+            (Value::Binary(a), Value::Binary(b)) => a == b,
+            #[cfg(feature = "chrono")]
+            (Value::ChronoNaiveTime(a), Value::ChronoNaiveTime(b)) => a == b,
+            #[cfg(feature = "chrono")]
+            (Value::ChronoNaiveDate(a), Value::ChronoNaiveDate(b)) => a == b,
+            #[cfg(feature = "chrono")]
+            (Value::ChronoNaiveDateTime(a), Value::ChronoNaiveDateTime(b)) => a == b,
+            #[cfg(feature = "chrono")]
+            (Value::ChronoDateTime(a), Value::ChronoDateTime(b)) => a == b,
+            #[cfg(feature = "time")]
+            (Value::TimeDate(a), Value::TimeDate(b)) => a == b,
+            #[cfg(feature = "time")]
+            (Value::TimeTime(a), Value::TimeTime(b)) => a == b,
+            #[cfg(feature = "time")]
+            (Value::TimeOffsetDateTime(a), Value::TimeOffsetDateTime(b)) => a == b,
+            #[cfg(feature = "time")]
+            (Value::TimePrimitiveDateTime(a), Value::TimePrimitiveDateTime(b)) => a == b,
+            #[cfg(feature = "uuid")]
+            (Value::Uuid(a), Value::Uuid(b)) => a == b,
+            #[cfg(feature = "postgres-only")]
+            (Value::MacAddress(a), Value::MacAddress(b)) => a == b,
+            #[cfg(feature = "postgres-only")]
+            (Value::IpNetwork(a), Value::IpNetwork(b)) => a == b,
+            #[cfg(feature = "postgres-only")]
+            (Value::BitVec(a), Value::BitVec(b)) => a.as_ref() == b.as_ref(),
+            (Value::Invalid(a), Value::Invalid(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+impl Eq for Value<'_> {}
+impl Hash for Value<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            Value::Null(v) => mem::discriminant(v).hash(state),
+            Value::String(v) => v.hash(state),
+            Value::Choice(v) => v.hash(state),
+            Value::I64(v) => v.hash(state),
+            Value::I32(v) => v.hash(state),
+            Value::I16(v) => v.hash(state),
+            Value::Bool(v) => v.hash(state),
+            Value::F64(v) => v.to_bits().hash(state),
+            Value::F32(v) => v.to_bits().hash(state),
+            Value::Binary(v) => v.hash(state),
+            #[cfg(feature = "chrono")]
+            Value::ChronoNaiveTime(v) => v.hash(state),
+            #[cfg(feature = "chrono")]
+            Value::ChronoNaiveDate(v) => v.hash(state),
+            #[cfg(feature = "chrono")]
+            Value::ChronoNaiveDateTime(v) => v.hash(state),
+            #[cfg(feature = "chrono")]
+            Value::ChronoDateTime(v) => v.hash(state),
+            #[cfg(feature = "time")]
+            Value::TimeDate(v) => v.hash(state),
+            #[cfg(feature = "time")]
+            Value::TimeTime(v) => v.hash(state),
+            #[cfg(feature = "time")]
+            Value::TimeOffsetDateTime(v) => v.hash(state),
+            #[cfg(feature = "time")]
+            Value::TimePrimitiveDateTime(v) => v.hash(state),
+            #[cfg(feature = "uuid")]
+            Value::Uuid(v) => v.hash(state),
+            #[cfg(feature = "postgres-only")]
+            Value::MacAddress(v) => v.hash(state),
+            #[cfg(feature = "postgres-only")]
+            Value::IpNetwork(v) => v.hash(state),
+            #[cfg(feature = "postgres-only")]
+            Value::BitVec(v) => v.as_ref().hash(state),
+            Value::Invalid(v) => v.hash(state),
         }
     }
 }
@@ -202,6 +379,7 @@ impl<'a> Condition<'a> for Value<'a> {
         context.conditions.push(FlatCondition::Value(index));
     }
 }
+impl<M: Model> ConditionOrigin<M> for Value<'_> {}
 
 /// A column name
 #[derive(Copy, Clone)]
@@ -215,6 +393,7 @@ impl<'a, I: FieldProxyImpl> Condition<'a> for Column<I> {
             .push(FlatCondition::Column(path_id, <I::Field as Field>::NAME))
     }
 }
+impl<M: Model, I: FieldProxyImpl> ConditionOrigin<M> for Column<I> where I::Path: Path<Origin = M> {}
 
 /// A binary expression
 #[derive(Copy, Clone)]
@@ -261,6 +440,7 @@ impl<'a, A: Condition<'a>, B: Condition<'a>> Condition<'a> for Binary<A, B> {
         self.snd_arg.build(context);
     }
 }
+impl<M: Model, A: ConditionOrigin<M>, B: ConditionOrigin<M>> ConditionOrigin<M> for Binary<A, B> {}
 
 /// A ternary expression
 #[derive(Copy, Clone)]
@@ -295,6 +475,10 @@ impl<'a, A: Condition<'a>, B: Condition<'a>, C: Condition<'a>> Condition<'a> for
         self.trd_arg.build(context);
     }
 }
+impl<M: Model, A: ConditionOrigin<M>, B: ConditionOrigin<M>, C: ConditionOrigin<M>>
+    ConditionOrigin<M> for Ternary<A, B, C>
+{
+}
 
 /// A unary expression
 #[derive(Copy, Clone)]
@@ -327,3 +511,4 @@ impl<'a, A: Condition<'a>> Condition<'a> for Unary<A> {
         self.fst_arg.build(context);
     }
 }
+impl<M: Model, A: ConditionOrigin<M>> ConditionOrigin<M> for Unary<A> {}
@@ -2,7 +2,7 @@
 
 use std::marker::PhantomData;
 
-use rorm_db::row::{DecodeOwned, RowError};
+use rorm_db::row::{DecodeOwned, RowError, RowIndex};
 use rorm_db::Row;
 
 /// Something which decodes a [value](Self::Result) from a [`&Row`](rorm_db::Row)
@@ -62,6 +62,16 @@ where
     }
 }
 
+/// Checks a row's column to be `NULL`
+///
+/// Some databases (i.e. SQLite) decode a `NULL` as the type's "zero value" like `0` or `""`
+/// instead of producing a [`RowError::UnexpectedNull`].
+/// Decoders producing an `Option` have to use this function to detect a `NULL` reliably.
+pub(crate) fn is_null<'i>(row: &Row, index: impl Into<RowIndex<'i>>) -> bool {
+    // A `NULL` skips the type check, so `bool` is as good as any other type.
+    matches!(row.get::<Option<bool>>(index), Ok(None))
+}
+
 /// A [`Decoder`] which "decodes" a value by using the [`Default`] trait
 ///
 /// This is a "noop" which doesn't touch the [`&Row`](rorm_db::Row) at all
@@ -147,6 +157,7 @@ pub trait DecoderExt: Decoder + Sized {
     //     }
     // }
 }
+impl<D: Decoder> DecoderExt for D {}
 
 /// [`Decoder`] returned by [`DecoderExt::map`]
 pub struct Map<D, F> {
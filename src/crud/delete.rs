@@ -1,15 +1,22 @@
 //! Delete builder and macro
 
 use std::marker::PhantomData;
+use std::num::NonZeroU64;
 
 use rorm_db::database;
 use rorm_db::error::Error;
-use rorm_db::executor::Executor;
+use rorm_db::executor::{AffectedRows, Executor};
+use rorm_db::sql;
+use rorm_db::sql::delete::Delete;
+use rorm_db::sql::limit_clause::LimitClause;
+use rorm_db::sql::select::Select;
 
-use crate::conditions::{Condition, DynamicCollection};
+use crate::conditions::{Condition, ConditionOrigin, DynamicCollection};
 use crate::crud::selector::Selector;
+use crate::internal::field::Field;
 use crate::internal::patch::{IntoPatchCow, PatchCow};
 use crate::internal::query_context::QueryContext;
+use crate::internal::relation_path::Path;
 use crate::model::{Identifiable, Model};
 use crate::Patch;
 
@@ -50,6 +57,7 @@ use crate::Patch;
 /// - [`single`](DeleteBuilder::single): Delete a single row identified by a patch instance
 /// - [`bulk`](DeleteBuilder::bulk): Delete a bulk of rows identified by patch instances
 /// - [`condition`](DeleteBuilder::condition): Delete all rows matching a condition
+/// - [`delete_in_batches`](DeleteBuilder::delete_in_batches): Delete all rows matching a condition in several smaller statements
 /// - [`all`](DeleteBuilder::all): Unconditionally delete all rows
 pub fn delete<'ex, E, S>(executor: E, _: S) -> DeleteBuilder<E, S::Model>
 where
@@ -147,9 +155,14 @@ where
     }
 
     /// Delete all rows matching a condition
-    pub async fn condition<'c, C: Condition<'c>>(self, condition: C) -> Result<u64, Error> {
+    pub async fn condition<'c, C>(self, condition: C) -> Result<u64, Error>
+    where
+        C: Condition<'c> + ConditionOrigin<M>,
+    {
         let mut context = QueryContext::new();
+        M::add_to_context(&mut context);
         let condition_index = context.add_condition(&condition);
+        context.check_values()?;
         database::delete(
             self.executor,
             M::TABLE,
@@ -158,6 +171,100 @@ where
         .await
     }
 
+    /// Delete all rows matching a condition in batches of at most `batch_size` rows
+    ///
+    /// Each batch is a single statement deleting the rows whose primary key is returned by a limited subquery:
+    /// `DELETE FROM ... WHERE id IN (SELECT id FROM (SELECT id FROM ... WHERE ... LIMIT n) AS batch)`.
+    /// This avoids locking a large table for a single long running statement
+    /// and doesn't require `DELETE ... LIMIT` which isn't supported by postgres.
+    /// (The additional derived table is required by mysql which doesn't support `LIMIT` in an `IN` subquery.)
+    ///
+    /// The statement is repeated until it deletes less than `batch_size` rows.
+    ///
+    /// The executor has to be [`Copy`] (i.e. a `&Database`) because each batch runs on its own.
+    /// (Running the batches in a single transaction would defeat their purpose anyway.)
+    ///
+    /// Returns the total number of deleted rows.
+    ///
+    /// ```no_run
+    /// # use std::num::NonZeroU64;
+    /// # use rorm::{Model, Database, delete};
+    /// # #[derive(Model)] pub struct Log { #[rorm(id)] id: i64, level: i32, }
+    /// pub async fn delete_debug_logs(db: &Database) {
+    ///     let batch_size = NonZeroU64::new(10_000).unwrap();
+    ///     let num_deleted: u64 = delete(db, Log)
+    ///         .delete_in_batches(Log.level.equals(0), batch_size)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn delete_in_batches<'c, C>(
+        self,
+        condition: C,
+        batch_size: NonZeroU64,
+    ) -> Result<u64, Error>
+    where
+        E: Copy,
+        C: Condition<'c> + ConditionOrigin<M>,
+    {
+        let dialect = self.executor.dialect();
+        let batch_size = batch_size.get();
+
+        let mut context = QueryContext::new();
+        let (_, alias) = context.select_field::<M::Primary, M>();
+        let condition_index = context.add_condition(&condition);
+        context.check_values()?;
+        let condition = context.get_condition(condition_index);
+
+        let columns: Vec<_> = context
+            .get_selects()
+            .iter()
+            .map(|c| {
+                dialect.select_column(c.table_name, c.column_name, c.select_alias, c.aggregation)
+            })
+            .collect();
+        let joins: Vec<_> = context
+            .get_joins()
+            .into_iter()
+            .map(|j| dialect.join_table(j.join_type, j.table_name, j.join_alias, j.join_condition))
+            .collect();
+        let (batch, values) = dialect
+            .select(&columns, M::TABLE, &joins, &[])
+            .where_clause(&condition)
+            .limit_clause(LimitClause {
+                limit: batch_size,
+                offset: None,
+            })
+            .build();
+
+        let subquery = format!(
+            "(SELECT {alias} FROM ({}) AS __batch)",
+            batch.trim_end().trim_end_matches(';')
+        );
+        let in_batch = sql::conditional::Condition::BinaryCondition(
+            sql::conditional::BinaryCondition::In(Box::new([
+                sql::conditional::Condition::Value(sql::value::Value::Column {
+                    table_name: Some(M::TABLE),
+                    column_name: <M::Primary as Field>::NAME,
+                }),
+                sql::conditional::Condition::Value(sql::value::Value::Ident(&subquery)),
+            ])),
+        );
+        let (statement, _) = dialect.delete(M::TABLE).where_clause(&in_batch).build();
+
+        let mut total = 0;
+        loop {
+            let deleted = self
+                .executor
+                .execute::<AffectedRows>(statement.clone(), values.clone())
+                .await?;
+            total += deleted;
+            if deleted < batch_size {
+                return Ok(total);
+            }
+        }
+    }
+
     /// Delete all rows
     pub async fn all(self) -> Result<u64, Error> {
         database::delete(self.executor, M::TABLE, None).await
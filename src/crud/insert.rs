@@ -5,6 +5,8 @@ use std::marker::PhantomData;
 use rorm_db::database;
 use rorm_db::error::Error;
 use rorm_db::executor::Executor;
+use rorm_db::sql;
+use rorm_db::sql::DBImpl;
 
 use crate::conditions::Value;
 use crate::crud::decoder::Decoder;
@@ -98,6 +100,116 @@ where
     }
 }
 
+/// Insert some patches and return the number of inserted rows
+///
+/// This is a shorthand for [`insert`] followed by
+/// [`return_nothing`](InsertBuilder::return_nothing) and [`bulk`](InsertReturningNothing::bulk).
+/// The model is taken from the patch type.
+///
+/// The patches are inserted in batches which fit into a single statement each
+/// (i.e. stay below the database's limit of bind parameters).
+/// All batches run in the same transaction, so either all or none of the patches are inserted.
+///
+/// If you need the inserted rows back (for example their generated primary keys),
+/// use [`InsertBuilder::bulk`] instead, which batches its patches the same way:
+/// `insert(db, User).return_patch::<User>().bulk(patches)`
+///
+/// # Argument
+/// This function accepts anything which can be used to iterate
+/// over instances or references of your [`Patch`].
+///
+/// **Examples**: (where `P` is your patch)
+/// - `Vec<P>`
+/// - `&[P]`
+/// - A [`map`](Iterator::map) iterator yielding `P` or `&P`
+///
+/// ```no_run
+/// # use rorm::{Model, Patch, Database, insert_many};
+/// # #[derive(Model)] pub struct User { #[rorm(id)] id: i64, #[rorm(max_length = 255)] name: String, }
+/// # #[derive(Patch)] #[rorm(model = "User")] pub struct NewUser { name: String, }
+/// pub async fn create_users(db: &Database) {
+///     let users = vec![
+///         NewUser { name: "alice".to_string() },
+///         NewUser { name: "bob".to_string() },
+///     ];
+///     let inserted = insert_many(db, users).await.unwrap();
+///     assert_eq!(inserted, 2);
+/// }
+/// ```
+pub async fn insert_many<'ex, 'p, E, I, P>(executor: E, patches: I) -> Result<u64, Error>
+where
+    E: Executor<'ex>,
+    I: IntoIterator,
+    I::Item: IntoPatchCow<'p, Patch = P>,
+    P: Patch,
+{
+    let (values, rows) = collect_values(patches);
+    if rows == 0 {
+        return Ok(0);
+    }
+
+    let columns = P::columns();
+    let values = values
+        .iter()
+        .map(Value::try_as_sql)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let dialect = executor.dialect();
+    let mut guard = executor.ensure_transaction().await?;
+    for batch in batch_rows(&values, columns.len(), dialect) {
+        database::insert_bulk(guard.get_transaction(), P::Model::TABLE, &columns, &batch).await?;
+    }
+    guard.commit().await?;
+    Ok(rows as u64)
+}
+
+/// Collects the values of some patches and counts them
+fn collect_values<'p, I, P>(patches: I) -> (Vec<Value<'p>>, usize)
+where
+    I: IntoIterator,
+    I::Item: IntoPatchCow<'p, Patch = P>,
+    P: Patch,
+{
+    let mut rows = 0;
+    let mut values = Vec::new();
+    for patch in patches {
+        match patch.into_patch_cow() {
+            PatchCow::Borrowed(patch) => patch.push_references(&mut values),
+            PatchCow::Owned(patch) => patch.push_values(&mut values),
+        }
+        rows += 1;
+    }
+    (values, rows)
+}
+
+/// Splits the values of several rows into batches which can each be inserted by a single statement
+///
+/// Every statement may only use a limited number of bind parameters,
+/// so a batch contains at most `max_bind_parameters(dialect) / columns` rows.
+fn batch_rows<'a, 'v>(
+    values: &'a [sql::value::Value<'v>],
+    columns: usize,
+    dialect: DBImpl,
+) -> Vec<Vec<&'a [sql::value::Value<'v>]>> {
+    let columns = columns.max(1);
+    let rows_per_batch = (max_bind_parameters(dialect) / columns).max(1);
+    values
+        .chunks(columns * rows_per_batch)
+        .map(|batch| batch.chunks(columns).collect())
+        .collect()
+}
+
+/// The maximum number of bind parameters a single statement may use
+fn max_bind_parameters(dialect: DBImpl) -> usize {
+    if matches!(dialect, DBImpl::Postgres) {
+        // postgres counts the parameters using a 16-bit integer
+        65535
+    } else {
+        // sqlite's default for `SQLITE_MAX_VARIABLE_NUMBER` (mysql would allow 65535 as well)
+        32766
+    }
+}
+
 /// Builder for insert queries
 ///
 /// Is is recommended to start a builder using [`insert!`](macro@crate::insert).
@@ -195,7 +307,10 @@ where
 
         let columns = P::columns();
         let values = patch.references();
-        let values: Vec<_> = values.iter().map(Value::as_sql).collect();
+        let values = values
+            .iter()
+            .map(Value::try_as_sql)
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut ctx = QueryContext::new();
         let decoder = self.selector.select(&mut ctx);
@@ -216,6 +331,9 @@ where
 
     /// Insert a bulk of patches into the db
     ///
+    /// The patches are inserted in batches which fit into a single statement each.
+    /// All batches run in the same transaction, so either all or none of the patches are inserted.
+    ///
     /// # Argument
     /// This method accepts anything which can be used to iterate
     /// over instances or references of your [`Patch`].
@@ -234,17 +352,13 @@ where
         #[allow(clippy::let_unit_value)]
         let _check = Self::CHECK;
 
-        let mut values: Vec<Value<'p>> = Vec::new();
-        for patch in patches {
-            match patch.into_patch_cow() {
-                PatchCow::Borrowed(patch) => patch.push_references(&mut values),
-                PatchCow::Owned(patch) => patch.push_values(&mut values),
-            }
-        }
+        let (values, _) = collect_values(patches);
 
         let columns = P::columns();
-        let values: Vec<_> = values.iter().map(Value::as_sql).collect();
-        let values_slices: Vec<_> = values.chunks(columns.len()).collect();
+        let values = values
+            .iter()
+            .map(Value::try_as_sql)
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut ctx = QueryContext::new();
         let decoder = self.selector.select(&mut ctx);
@@ -252,17 +366,24 @@ where
             .get_returning()
             .expect("Should have been checked in set_select");
 
-        let rows = database::insert_bulk_returning(
-            self.executor,
-            M::TABLE,
-            &columns,
-            &values_slices,
-            &returning,
-        )
-        .await?;
-        rows.iter()
-            .map(|row| decoder.by_index(row).map_err(Into::into))
-            .collect()
+        let dialect = self.executor.dialect();
+        let mut guard = self.executor.ensure_transaction().await?;
+        let mut results = Vec::new();
+        for batch in batch_rows(&values, columns.len(), dialect) {
+            let rows = database::insert_bulk_returning(
+                guard.get_transaction(),
+                M::TABLE,
+                &columns,
+                &batch,
+                &returning,
+            )
+            .await?;
+            for row in &rows {
+                results.push(decoder.by_index(row)?);
+            }
+        }
+        guard.commit().await?;
+        Ok(results)
     }
 }
 
@@ -281,7 +402,10 @@ where
     pub async fn single<P: Patch<Model = M>>(self, patch: &P) -> Result<(), Error> {
         let columns = P::columns();
         let values = patch.references();
-        let values: Vec<_> = values.iter().map(Value::as_sql).collect();
+        let values = values
+            .iter()
+            .map(Value::try_as_sql)
+            .collect::<Result<Vec<_>, _>>()?;
 
         database::insert(self.executor, M::TABLE, &columns, &values).await
     }
@@ -293,19 +417,7 @@ where
         I::Item: IntoPatchCow<'p, Patch = P>,
         P: Patch<Model = M>,
     {
-        let mut values: Vec<Value<'p>> = Vec::new();
-        for patch in patches {
-            match patch.into_patch_cow() {
-                PatchCow::Borrowed(patch) => patch.push_references(&mut values),
-                PatchCow::Owned(patch) => patch.push_values(&mut values),
-            }
-        }
-
-        let columns = P::columns();
-        let values: Vec<_> = values.iter().map(Value::as_sql).collect();
-        let values_slices: Vec<_> = values.chunks(columns.len()).collect();
-
-        database::insert_bulk(self.executor, M::TABLE, &columns, &values_slices).await
+        insert_many(self.executor, patches).await.map(|_| ())
     }
 }
 
@@ -319,3 +431,26 @@ macro_rules! insert {
         )
     };
 }
+
+#[cfg(test)]
+mod test {
+    use rorm_db::sql::value::{NullType, Value};
+    use rorm_db::sql::DBImpl;
+
+    use super::batch_rows;
+
+    #[test]
+    #[cfg(feature = "all-drivers")]
+    fn batch_rows_respects_parameter_limit() {
+        let values = vec![Value::Null(NullType::I64); 3 * 20_000];
+
+        let batches = batch_rows(&values, 3, DBImpl::SQLite);
+        let rows: Vec<_> = batches.iter().map(Vec::len).collect();
+        assert_eq!(rows, [10922, 9078]);
+        assert!(batches.iter().flatten().all(|row| row.len() == 3));
+
+        let batches = batch_rows(&values, 3, DBImpl::Postgres);
+        let rows: Vec<_> = batches.iter().map(Vec::len).collect();
+        assert_eq!(rows, [20_000]);
+    }
+}
@@ -16,5 +16,6 @@ pub mod decoder;
 pub mod delete;
 pub mod insert;
 pub mod query;
+pub mod raw;
 pub mod selector;
 pub mod update;
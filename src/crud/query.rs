@@ -8,10 +8,10 @@ use rorm_db::executor::{All, Executor, One, Optional, Stream};
 use rorm_db::sql::limit_clause::LimitClause;
 use rorm_db::sql::ordering::Ordering;
 
-use crate::conditions::Condition;
+use crate::conditions::{Condition, ConditionOrigin};
 use crate::crud::builder::ConditionMarker;
 use crate::crud::decoder::Decoder;
-use crate::crud::selector::Selector;
+use crate::crud::selector::{MappedSelector, Selector};
 use crate::internal::query_context::QueryContext;
 use crate::internal::relation_path::Path;
 use crate::model::Model;
@@ -39,6 +39,10 @@ use crate::sealed;
 ///
 ///     `.range(2..7)`
 ///
+/// 4. *Optionally* apply a function to every decoded result. ([`map_result`](QueryBuilder::map_result))
+///
+///     `.map_result(|(id, name)| format!("{id}: {name}"))`
+///
 /// 5. Finally specify how to get the queries results. This will also execute the query.
 ///     - Get [`all`](QueryBuilder::all) matching rows in a vector.
 ///
@@ -56,6 +60,15 @@ use crate::sealed;
 ///
 ///         `.optional().await`
 ///
+///     - Get the row with the lowest or highest value in a field if any.
+///         ([`first`](QueryBuilder::first) / [`last`](QueryBuilder::last))
+///
+///         `.last(MyModelType.created_at).await`
+///
+///     - Get a single field of all matching rows in a vector. ([`values`](QueryBuilder::values))
+///
+///         `.values(MyModelType.id).await`
+///
 ///     Each of these methods decodes the database's rows into the patch you specified in step 1.
 ///     If you want to work with raw rows, each of the methods in step 4 has a `*_as_row` twin.
 ///
@@ -145,9 +158,17 @@ where
     }
 }
 
-impl<E, S, LO> QueryBuilder<E, S, (), LO> {
+impl<E, S, LO> QueryBuilder<E, S, (), LO>
+where
+    S: Selector,
+{
     /// Add a condition to the query
-    pub fn condition<'c, C: Condition<'c>>(self, condition: C) -> QueryBuilder<E, S, C, LO> {
+    ///
+    /// The condition may only use fields reachable from the selected model.
+    pub fn condition<'c, C>(self, condition: C) -> QueryBuilder<E, S, C, LO>
+    where
+        C: Condition<'c> + ConditionOrigin<S::Model>,
+    {
         #[rustfmt::skip]
         let QueryBuilder { executor, selector, lim_off, modify_ctx, .. } = self;
         #[rustfmt::skip]
@@ -203,6 +224,34 @@ where
     /// Order the query by a field
     ///
     /// You can add multiple orderings from most to least significant.
+    ///
+    /// The field may belong to a related model.
+    /// Its relation path is joined automatically:
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # use rorm::fields::types::ForeignModel;
+    /// # use rorm::db::sql::ordering::Ordering;
+    /// # #[derive(Model)]
+    /// # struct User {
+    /// #     #[rorm(id)]
+    /// #     id: i64,
+    /// #     #[rorm(max_length = 255)]
+    /// #     name: String,
+    /// # }
+    /// # #[derive(Model)]
+    /// # struct Comment {
+    /// #     #[rorm(id)]
+    /// #     id: i64,
+    /// #     user: ForeignModel<User>,
+    /// # }
+    /// pub async fn comments_by_author(db: &Database) -> Vec<Comment> {
+    ///     query(db, Comment)
+    ///         .order_by(Comment.user.name, Ordering::Asc)
+    ///         .all()
+    ///         .await
+    ///         .unwrap()
+    /// }
+    /// ```
     pub fn order_by<I>(mut self, _field: FieldProxy<I>, order: Ordering) -> Self
     where
         I: FieldProxyImpl<Path: Path<Origin = S::Model>>,
@@ -237,6 +286,39 @@ where
     {
         self.order_by(field, Ordering::Desc)
     }
+
+    /// Apply a function to every decoded result
+    ///
+    /// The function is run by the decoder,
+    /// so it applies to every method retrieving results (i.e. `all`, `stream`, `one`, etc.).
+    ///
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # #[derive(Model)]
+    /// # struct User {
+    /// #     #[rorm(id)]
+    /// #     id: i64,
+    /// #     #[rorm(max_length = 255)]
+    /// #     username: String,
+    /// # }
+    /// pub async fn shouted_usernames(db: &Database) -> Vec<String> {
+    ///     query(db, User.username)
+    ///         .map_result(|username| username.to_uppercase())
+    ///         .all()
+    ///         .await
+    ///         .unwrap()
+    /// }
+    /// ```
+    pub fn map_result<F, U>(self, function: F) -> QueryBuilder<E, MappedSelector<S, F>, C, LO>
+    where
+        F: Fn(S::Result) -> U,
+    {
+        #[rustfmt::skip]
+        let QueryBuilder { executor, selector, condition, lim_off, modify_ctx, } = self;
+        let selector = MappedSelector { selector, function };
+        #[rustfmt::skip]
+        return QueryBuilder { executor, selector, condition, lim_off, modify_ctx, };
+    }
 }
 
 impl<'e, 'c, E, S, C, LO> QueryBuilder<E, S, C, LO>
@@ -258,6 +340,7 @@ where
             modify(&mut ctx);
         }
 
+        ctx.check_values()?;
         let condition = ctx.get_condition_opt(condition_index);
 
         database::query::<All>(
@@ -291,7 +374,8 @@ where
             modify(&mut ctx);
         }
 
-        QueryStream::new(decoder, ctx, move |ctx| {
+        let error = ctx.check_values().err();
+        QueryStream::new(decoder, ctx, error, move |ctx| {
             database::query::<Stream>(
                 self.executor,
                 S::Model::TABLE,
@@ -304,6 +388,62 @@ where
         })
     }
 
+    /// Retrieve and decode the query and write each result as a line of json
+    ///
+    /// The results are written one by one as they arrive from the database
+    /// without collecting the whole result set.
+    ///
+    /// Returns the number of written lines.
+    ///
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # #[derive(Model)]
+    /// # struct User {
+    /// #     #[rorm(id)]
+    /// #     id: i64,
+    /// #     #[rorm(max_length = 255)]
+    /// #     username: String,
+    /// # }
+    /// pub async fn export_users(db: &Database, writer: impl futures_io::AsyncWrite + Unpin) {
+    ///     query(db, (User.id, User.username))
+    ///         .stream_ndjson(writer)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    #[cfg(feature = "ndjson")]
+    pub async fn stream_ndjson<W>(self, mut writer: W) -> Result<u64, NdjsonError>
+    where
+        LO: LimitMarker,
+        S::Result: serde::Serialize,
+        W: futures_io::AsyncWrite + Unpin,
+    {
+        use std::future::poll_fn;
+        use std::pin::{pin, Pin};
+
+        use futures_core::Stream as _;
+
+        let mut stream = pin!(self.stream());
+        let mut line = Vec::new();
+        let mut lines = 0;
+        while let Some(result) = poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+            line.clear();
+            serde_json::to_writer(&mut line, &result?)?;
+            line.push(b'\n');
+
+            let mut buf = line.as_slice();
+            while !buf.is_empty() {
+                match poll_fn(|cx| Pin::new(&mut writer).poll_write(cx, buf)).await? {
+                    0 => return Err(std::io::Error::from(std::io::ErrorKind::WriteZero).into()),
+                    written => buf = &buf[written..],
+                }
+            }
+            lines += 1;
+        }
+        poll_fn(|cx| Pin::new(&mut writer).poll_flush(cx)).await?;
+        Ok(lines)
+    }
+
     /// Retrieve and decode exactly one matching row
     ///
     /// An error is returned if no value could be retrieved.
@@ -319,6 +459,7 @@ where
             modify(&mut ctx);
         }
 
+        ctx.check_values()?;
         let row = database::query::<One>(
             self.executor,
             S::Model::TABLE,
@@ -345,6 +486,7 @@ where
             modify(&mut ctx);
         }
 
+        ctx.check_values()?;
         let row = database::query::<Optional>(
             self.executor,
             S::Model::TABLE,
@@ -362,6 +504,96 @@ where
     }
 }
 
+impl<'e, 'c, E, S, C, LO> QueryBuilder<E, S, C, LO>
+where
+    E: Executor<'e>,
+    S: Selector,
+    C: ConditionMarker<'c>,
+    LO: LimitMarker,
+{
+    /// Retrieve and decode a single field of all matching rows
+    ///
+    /// This replaces the builder's selector with the field,
+    /// while keeping the condition, limit, offset and orderings.
+    ///
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # #[derive(Model)]
+    /// # struct User {
+    /// #     #[rorm(id)]
+    /// #     id: i64,
+    /// #     admin: bool,
+    /// # }
+    /// pub async fn admin_ids(db: &Database) -> Vec<i64> {
+    ///     query(db, User)
+    ///         .condition(User.admin.equals(true))
+    ///         .values(User.id)
+    ///         .await
+    ///         .unwrap()
+    /// }
+    /// ```
+    pub async fn values<I>(
+        self,
+        field: FieldProxy<I>,
+    ) -> Result<Vec<<I::Field as Field>::Type>, Error>
+    where
+        I: FieldProxyImpl<Path: Path<Origin = S::Model>>,
+    {
+        #[rustfmt::skip]
+        let QueryBuilder { executor, condition, lim_off, modify_ctx, .. } = self;
+        #[rustfmt::skip]
+        return QueryBuilder { executor, selector: field, condition, lim_off, modify_ctx, }.all().await;
+    }
+}
+
+impl<'e, 'c, E, S, C, O> QueryBuilder<E, S, C, O>
+where
+    E: Executor<'e>,
+    S: Selector,
+    C: ConditionMarker<'c>,
+    O: OffsetMarker,
+{
+    /// Retrieve and decode the matching row with the lowest value in `field`
+    ///
+    /// This is a shorthand for `.order_asc(field).optional()`.
+    /// Like [`order_by`](QueryBuilder::order_by), the ordering is added after any previous ones.
+    pub async fn first<I>(self, field: FieldProxy<I>) -> Result<Option<S::Result>, Error>
+    where
+        I: FieldProxyImpl<Path: Path<Origin = S::Model>>,
+    {
+        self.order_asc(field).optional().await
+    }
+
+    /// Retrieve and decode the matching row with the highest value in `field`
+    ///
+    /// This is a shorthand for `.order_desc(field).optional()`.
+    /// Like [`order_by`](QueryBuilder::order_by), the ordering is added after any previous ones.
+    ///
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # #[derive(Model)]
+    /// # struct Post {
+    /// #     #[rorm(id)]
+    /// #     id: i64,
+    /// #     #[rorm(max_length = 255)]
+    /// #     thread: String,
+    /// # }
+    /// pub async fn latest_post(db: &Database, thread: &str) -> Option<Post> {
+    ///     query(db, Post)
+    ///         .condition(Post.thread.equals(thread))
+    ///         .last(Post.id)
+    ///         .await
+    ///         .unwrap()
+    /// }
+    /// ```
+    pub async fn last<I>(self, field: FieldProxy<I>) -> Result<Option<S::Result>, Error>
+    where
+        I: FieldProxyImpl<Path: Path<Origin = S::Model>>,
+    {
+        self.order_desc(field).optional().await
+    }
+}
+
 #[doc(hidden)]
 #[deprecated(note = "Use the query function instead i.e. remove the `!`")]
 #[macro_export]
@@ -409,14 +641,18 @@ mod query_stream {
 
         ctx: Box<QueryContext<'cond>>,
 
+        /// Error to yield instead of running the query
+        error: Option<Error>,
+
         #[pin]
-        stream: <Stream as QueryStrategyResult>::Result<'this>,
+        stream: Option<<Stream as QueryStrategyResult>::Result<'this>>,
     }
 
     impl<'this, 'cond: 'this, D> QueryStream<'this, 'cond, D> {
         pub(crate) fn new(
             decoder: D,
             ctx: QueryContext<'cond>,
+            error: Option<Error>,
             stream_builder: impl FnOnce(
                 &'this QueryContext<'cond>,
             ) -> <Stream as QueryStrategyResult>::Result<'this>,
@@ -431,11 +667,12 @@ mod query_stream {
                 let ctx = Box::new(ctx);
                 let ctx_ref: &'this QueryContext<'cond> = change_lifetime(ctx.as_ref());
 
-                let stream = stream_builder(ctx_ref);
+                let stream = error.is_none().then(|| stream_builder(ctx_ref));
 
                 Self {
                     ctx,
                     decoder,
+                    error,
                     stream,
                 }
             }
@@ -446,8 +683,14 @@ mod query_stream {
         type Item = Result<D::Result, Error>;
 
         fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-            let mut projection = self.project();
-            projection.stream.as_mut().poll_next(cx).map(|option| {
+            let projection = self.project();
+            if let Some(error) = projection.error.take() {
+                return Poll::Ready(Some(Err(error)));
+            }
+            let Some(stream) = projection.stream.as_pin_mut() else {
+                return Poll::Ready(None);
+            };
+            stream.poll_next(cx).map(|option| {
                 option.map(|result| result.and_then(|row| Ok(projection.decoder.by_name(&row)?)))
             })
         }
@@ -456,6 +699,51 @@ mod query_stream {
 use query_stream::QueryStream;
 
 use crate::fields::proxy::{FieldProxy, FieldProxyImpl};
+use crate::internal::field::Field;
+
+/// Error returned by [`QueryBuilder::stream_ndjson`]
+#[cfg(feature = "ndjson")]
+#[derive(Debug)]
+pub enum NdjsonError {
+    /// The query failed
+    Database(Error),
+
+    /// A result could not be serialized
+    Json(serde_json::Error),
+
+    /// The writer failed
+    Io(std::io::Error),
+}
+#[cfg(feature = "ndjson")]
+impl From<Error> for NdjsonError {
+    fn from(error: Error) -> Self {
+        Self::Database(error)
+    }
+}
+#[cfg(feature = "ndjson")]
+impl From<serde_json::Error> for NdjsonError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+#[cfg(feature = "ndjson")]
+impl From<std::io::Error> for NdjsonError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+#[cfg(feature = "ndjson")]
+impl std::fmt::Display for NdjsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Database(error) => write!(f, "failed to query results: {error}"),
+            Self::Json(error) => write!(f, "failed to serialize result: {error}"),
+            Self::Io(error) => write!(f, "failed to write result: {error}"),
+        }
+    }
+}
+#[cfg(feature = "ndjson")]
+impl std::error::Error for NdjsonError {}
 
 /// Finite alternative to [`RangeBounds`](std::ops::RangeBounds)
 ///
@@ -0,0 +1,66 @@
+//! Escape hatch to run hand-written sql
+
+use rorm_db::error::Error;
+use rorm_db::executor::{All, Executor};
+
+use crate::conditions::Value;
+use crate::crud::decoder::Decoder;
+use crate::crud::selector::Selector;
+use crate::internal::query_context::QueryContext;
+
+/// Run a hand-written `SELECT` statement and decode its rows using a [`Selector`]
+///
+/// This is meant for queries which can't be expressed using the [`query`](crate::query) builder (yet).
+///
+/// The rows are decoded by name, i.e. every column is looked up using the name of the field it decodes.
+/// An aggregated column is looked up using the aggregation's name followed by the field's name,
+/// for example `count_id` for `Post.id.count()`.
+/// The statement may select the columns in any order,
+/// but a missing column is reported as error.
+/// Since two fields with the same name can't be told apart,
+/// the selector should not contain the same name twice.
+///
+/// The sql is passed to the database as is, so it has to use the placeholder syntax of the database in use.
+///
+/// ```no_run
+/// # use rorm::{Model, Patch, Database};
+/// # use rorm::conditions::Value;
+/// # use rorm::crud::raw::query_raw;
+/// # use rorm::fields::types::ForeignModel;
+/// # #[derive(Model)] pub struct User { #[rorm(id)] id: i64, #[rorm(max_length = 255)] name: String, }
+/// # #[derive(Model)] pub struct Post { #[rorm(id)] id: i64, author: ForeignModel<User>, }
+/// pub async fn count_posts_per_author(db: &Database) -> Vec<(String, i64)> {
+///     query_raw(
+///         db,
+///         (Post.author.name, Post.id.count()),
+///         r#"SELECT "user"."name", COUNT("post"."id") AS count_id FROM "user" JOIN "post" ON "post"."author" = "user"."id" WHERE "user"."name" != ? GROUP BY "user"."id""#,
+///         &[Value::String("admin".into())],
+///     )
+///     .await
+///     .unwrap()
+/// }
+/// ```
+pub async fn query_raw<'ex, E, S>(
+    executor: E,
+    selector: S,
+    sql: &str,
+    binds: &[Value<'_>],
+) -> Result<Vec<S::Result>, Error>
+where
+    E: Executor<'ex>,
+    S: Selector,
+{
+    let mut ctx = QueryContext::new();
+    ctx.use_column_names_as_aliases();
+    let decoder = selector.select(&mut ctx);
+    let binds = binds
+        .iter()
+        .map(Value::try_as_sql)
+        .collect::<Result<Vec<_>, _>>()?;
+    executor
+        .execute::<All>(sql.to_string(), binds)
+        .await?
+        .iter()
+        .map(|row| decoder.by_name(row).map_err(Into::into))
+        .collect()
+}
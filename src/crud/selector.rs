@@ -2,13 +2,15 @@
 
 use std::marker::PhantomData;
 
-use rorm_db::row::DecodeOwned;
+use rorm_db::row::{DecodeOwned, RowError};
 use rorm_db::sql::aggregation::SelectAggregator;
+use rorm_db::Row;
 
-use crate::crud::decoder::{Decoder, DirectDecoder};
-use crate::fields::proxy::{FieldProxy, FieldProxyImpl};
-use crate::fields::traits::FieldType;
+use crate::crud::decoder::{Decoder, DecoderExt, DirectDecoder, Map};
+use crate::fields::proxy::{self, FieldProxy, FieldProxyImpl};
+use crate::fields::traits::{FieldType, OptionDecoder};
 use crate::internal::field::decoder::FieldDecoder;
+use crate::internal::field::fake_field::FakeField;
 use crate::internal::field::Field;
 use crate::internal::query_context::QueryContext;
 use crate::internal::relation_path::Path;
@@ -73,6 +75,133 @@ where
     }
 }
 
+/// Combinator which wraps a selector to apply a function to its results.
+///
+/// Use [`QueryBuilder::map_result`](crate::crud::query::QueryBuilder::map_result) to construct it.
+pub struct MappedSelector<S, F> {
+    /// The wrapped selector
+    pub selector: S,
+    /// The function applied to every decoded result
+    pub function: F,
+}
+
+impl<S, F, U> Selector for MappedSelector<S, F>
+where
+    S: Selector,
+    F: Fn(S::Result) -> U,
+{
+    type Result = U;
+    type Model = S::Model;
+    type Decoder = Map<S::Decoder, F>;
+    const INSERT_COMPATIBLE: bool = S::INSERT_COMPATIBLE;
+
+    fn select(self, ctx: &mut QueryContext) -> Self::Decoder {
+        self.selector.select(ctx).map(self.function)
+    }
+}
+
+/// Combinator which wraps a [`PathedSelector`] to decode `None` if the related model's row is missing.
+///
+/// The path's tables are joined using `LEFT JOIN`, so rows without a related model are still returned.
+/// In those rows all the related model's columns are `NULL`.
+///
+/// To distinguish a missing row from a row whose selected columns are legitimately all `NULL`,
+/// the related model's primary key is selected in addition and checked for `NULL`.
+///
+/// ```
+/// # use rorm::crud::selector::{OptionalPatch, Selector};
+/// # use rorm::internal::query_context::QueryContext;
+/// # use rorm::fields::types::ForeignModel;
+/// # use rorm::db::sql::join_table::JoinType;
+/// # use rorm::prelude::*;
+/// #[derive(Model)]
+/// struct User {
+///     #[rorm(id)]
+///     id: i64,
+///     #[rorm(max_length = 255)]
+///     name: String,
+/// }
+///
+/// #[derive(Model)]
+/// struct Comment {
+///     #[rorm(id)]
+///     id: i64,
+///     user: Option<ForeignModel<User>>,
+/// }
+///
+/// // Selects each comment's id together with its author's name, if it has an author
+/// let selector = (Comment.id, OptionalPatch(Comment.user.query_as(User.name)));
+///
+/// let mut ctx = QueryContext::new();
+/// selector.select(&mut ctx);
+/// let joins = ctx.get_joins();
+/// assert!(matches!(joins[0].join_type, JoinType::LeftJoin));
+///
+/// // The related model's primary key is selected from the joined table
+/// let selects = ctx.get_selects();
+/// assert_eq!(selects[1].column_name, "id");
+/// assert_eq!(selects[1].table_name, Some(joins[0].join_alias));
+/// ```
+pub struct OptionalPatch<S>(pub S);
+
+impl<S, P> Selector for OptionalPatch<PathedSelector<S, P>>
+where
+    S: Selector,
+    P: Path<Current = S::Model>,
+{
+    type Result = Option<S::Result>;
+    type Model = P::Origin;
+    type Decoder = OptionalPatchDecoder<S::Decoder, <S::Model as Model>::Primary>;
+    const INSERT_COMPATIBLE: bool = false;
+
+    fn select(self, ctx: &mut QueryContext) -> Self::Decoder {
+        ctx.with_left_joins(|ctx| {
+            let mut ctx = ctx.with_base_path::<P>();
+            let primary = OptionDecoder::new(
+                &mut ctx,
+                proxy::new::<(
+                    FakeField<
+                        Option<<<S::Model as Model>::Primary as Field>::Type>,
+                        <S::Model as Model>::Primary,
+                    >,
+                    S::Model,
+                )>(),
+            );
+            OptionalPatchDecoder {
+                primary,
+                decoder: self.0.selector.select(&mut ctx),
+            }
+        })
+    }
+}
+
+/// [`Decoder`] returned by [`OptionalPatch`]
+pub struct OptionalPatchDecoder<D, P: Field> {
+    primary: OptionDecoder<P::Type>,
+    decoder: D,
+}
+impl<D, P> Decoder for OptionalPatchDecoder<D, P>
+where
+    D: Decoder,
+    P: Field,
+{
+    type Result = Option<D::Result>;
+
+    fn by_name<'index>(&'index self, row: &'_ Row) -> Result<Self::Result, RowError<'index>> {
+        match self.primary.by_name(row)? {
+            Some(_) => self.decoder.by_name(row).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn by_index<'index>(&'index self, row: &'_ Row) -> Result<Self::Result, RowError<'index>> {
+        match self.primary.by_index(row)? {
+            Some(_) => self.decoder.by_index(row).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
 /// A column to select and call an aggregation function on
 #[derive(Copy, Clone)]
 pub struct AggregatedColumn<I, R> {
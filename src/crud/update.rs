@@ -6,12 +6,13 @@ use rorm_db::database;
 use rorm_db::error::Error;
 use rorm_db::executor::Executor;
 
-use crate::conditions::{Condition, DynamicCollection, Value};
+use crate::conditions::{Condition, ConditionOrigin, DynamicCollection, Value};
 use crate::crud::selector::Selector;
 use crate::fields::proxy::{FieldProxy, FieldProxyImpl};
 use crate::internal::field::{Field, SingleColumnField};
 use crate::internal::patch::{IntoPatchCow, PatchCow};
 use crate::internal::query_context::QueryContext;
+use crate::internal::relation_path::Path;
 use crate::model::Identifiable;
 use crate::{Model, Patch};
 
@@ -170,6 +171,14 @@ impl<'rf, E, M> UpdateBuilder<'rf, E, M, columns::MaybeEmpty> {
         self
     }
 
+    /// Add all columns from a [`partial!`](crate::partial) to update.
+    ///
+    /// Can be called multiple times.
+    pub fn set_partial(mut self, partial: Partial<M>) -> Self {
+        self.columns.extend(partial.columns);
+        self
+    }
+
     /// Add a column to update if `value` is `Some`
     ///
     /// Can be called multiple times.
@@ -221,6 +230,17 @@ where
         ));
         self.set_column_state()
     }
+
+    /// Add all columns from a [`partial!`](crate::partial) to update.
+    ///
+    /// Can be called multiple times.
+    pub fn set_partial(
+        mut self,
+        partial: Partial<M>,
+    ) -> UpdateBuilder<'rf, E, M, columns::NonEmpty> {
+        self.columns.extend(partial.columns);
+        self.set_column_state()
+    }
 }
 
 impl<E, M> UpdateBuilder<'_, E, M, columns::NonEmpty>
@@ -240,6 +260,14 @@ where
         ));
         self
     }
+
+    /// Add all columns from a [`partial!`](crate::partial) to update.
+    ///
+    /// Can be called multiple times.
+    pub fn set_partial(mut self, partial: Partial<M>) -> Self {
+        self.columns.extend(partial.columns);
+        self
+    }
 }
 
 impl<'ex, 'rf, E, M> UpdateBuilder<'rf, E, M, columns::NonEmpty>
@@ -294,14 +322,19 @@ where
     }
 
     /// Update all rows matching a condition
-    pub async fn condition<C: Condition<'rf>>(self, condition: C) -> Result<u64, Error> {
+    pub async fn condition<C>(self, condition: C) -> Result<u64, Error>
+    where
+        C: Condition<'rf> + ConditionOrigin<M>,
+    {
         let mut context = QueryContext::new();
+        M::add_to_context(&mut context);
         let columns: Vec<_> = self
             .columns
             .iter()
-            .map(|(name, value)| (*name, value.as_sql()))
-            .collect();
+            .map(|(name, value)| Ok((*name, value.try_as_sql()?)))
+            .collect::<Result<_, Error>>()?;
         let condition_index = context.add_condition(&condition);
+        context.check_values()?;
         let condition = context.get_condition(condition_index);
         database::update(self.executor, M::TABLE, &columns, Some(&condition)).await
     }
@@ -311,12 +344,100 @@ where
         let columns: Vec<_> = self
             .columns
             .iter()
-            .map(|(name, value)| (*name, value.as_sql()))
-            .collect();
+            .map(|(name, value)| Ok((*name, value.try_as_sql()?)))
+            .collect::<Result<_, Error>>()?;
         database::update(self.executor, M::TABLE, &columns, None).await
     }
 }
 
+/// A set of columns and their new values, created using [`partial!`](crate::partial)
+///
+/// Pass it to [`UpdateBuilder::set_partial`] to update all of them at once.
+#[must_use]
+pub struct Partial<M> {
+    columns: Vec<(&'static str, Value<'static>)>,
+    _phantom: PhantomData<M>,
+}
+
+impl<M: Model> Partial<M> {
+    /// Creates an empty `Partial` which [`partial!`](crate::partial) adds at least one column to
+    ///
+    /// It is exposed to the macro as [`new_partial`](crate::internal::new_partial).
+    pub(crate) fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// **Use [`partial!`](crate::partial), this method is its impl detail!**
+    #[doc(hidden)]
+    pub fn set<I>(mut self, _field: FieldProxy<I>, value: <I::Field as Field>::Type) -> Self
+    where
+        I: FieldProxyImpl<Field: SingleColumnField, Path = M>,
+    {
+        self.columns.push((
+            <I::Field as Field>::NAME,
+            <I::Field as SingleColumnField>::type_into_value(value),
+        ));
+        self
+    }
+}
+
+/// Create a [`Partial`] i.e. some of a model's columns with their new values
+///
+/// The syntax resembles a struct literal which only lists the fields to update.
+/// Every field is checked at compile time to exist on the model and to match the value's type.
+///
+/// ```no_run
+/// # use rorm::{Model, Database, update, partial};
+/// #[derive(Model)]
+/// struct User {
+///     #[rorm(id)]
+///     id: i64,
+///     #[rorm(max_length = 255)]
+///     nickname: String,
+///     age: i16,
+/// }
+///
+/// pub async fn update_profile(db: &Database, id: i64, nickname: String, age: i16) {
+///     update(db, User)
+///         .set_partial(partial!(User { nickname, age }))
+///         .condition(User.id.equals(id))
+///         .await
+///         .unwrap();
+///
+///     update(db, User)
+///         .set_partial(partial!(User {
+///             nickname: "anonymous".to_string(),
+///             age: 0,
+///         }))
+///         .condition(User.id.equals(id))
+///         .await
+///         .unwrap();
+/// }
+/// ```
+///
+/// Fields which don't exist are rejected:
+/// ```compile_fail
+/// # use rorm::{Model, partial};
+/// # #[derive(Model)] struct User { #[rorm(id)] id: i64, #[rorm(max_length = 255)] nickname: String, }
+/// let _ = partial!(User { name: "alice".to_string() });
+/// ```
+#[macro_export]
+macro_rules! partial {
+    ($model:ident { $($field:ident $(: $value:expr)?),+ $(,)? }) => {
+        $crate::internal::new_partial::<$model>()
+            $(.set($model.$field, $crate::partial!(@value $field $($value)?)))+
+    };
+    (@value $field:ident $value:expr) => {
+        $value
+    };
+    (@value $field:ident) => {
+        $field
+    };
+}
+
 #[doc(hidden)]
 #[deprecated(note = "Use the query function instead i.e. remove the `!`")]
 #[macro_export]
@@ -9,6 +9,9 @@
 //! - [`f64`]
 //! - [`String`]
 //! - [`Vec<u8>`]
+//! - [`IpAddr`](std::net::IpAddr), [`Ipv4Addr`](std::net::Ipv4Addr) and [`Ipv6Addr`](std::net::Ipv6Addr)
+//!   (stored as `inet` with the "postgres-only" feature and as string otherwise)
+//! - [`PathBuf`](std::path::PathBuf) (stored as string, has to be valid UTF-8)
 //! - [`Option<T>`] where `T` is on this list
 //!
 //! # Our types
@@ -17,6 +20,7 @@
 //! - [`Json<T>`](types::Json)
 //! - [`MsgPack<T>`](types::MsgPack) (requires the "msgpack" feature)
 //! - [`MaxStr`](types::MaxStr)
+//! - [`Encrypted<T, C>`](types::Encrypted)
 //!
 //! # chrono types (requires the "chrono" feature)
 //! - [`NaiveDateTime`](chrono::NaiveDateTime)
@@ -36,6 +40,9 @@
 //! # url types (requires the "url" feature)
 //! - [`Url`](url::Url)
 //!
+//! # bytes types (requires the "bytes" feature)
+//! - [`Bytes`](bytes::Bytes)
+//!
 //! ---
 //!
 //! ```no_run
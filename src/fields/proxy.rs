@@ -5,7 +5,10 @@ use std::mem::ManuallyDrop;
 
 use rorm_db::sql::aggregation::SelectAggregator;
 
-use crate::conditions::{Binary, Column, In, InOperator, Unary, UnaryOperator, Value};
+use crate::conditions::collections::CollectionOperator;
+use crate::conditions::{
+    Binary, Column, In, InOperator, StaticCollection, Unary, UnaryOperator, Value,
+};
 use crate::crud::selector::{AggregatedColumn, PathedSelector, Selector};
 use crate::fields::traits::{
     FieldAvg, FieldColumns, FieldCount, FieldEq, FieldLike, FieldMax, FieldMin, FieldOrd,
@@ -36,6 +39,46 @@ where
     I: FieldProxyImpl<Field = F, Path = P>,
 {
     /// Query the model this field points to using `selector`
+    ///
+    /// The `selector` is written as if it queried the related model directly.
+    /// This method scopes it to the relation path, so its columns are taken from the correct join.
+    /// It is the type-safe alternative to [`QueryContext::with_base_path`](crate::internal::query_context::QueryContext::with_base_path):
+    /// ```
+    /// # use rorm::crud::selector::Selector;
+    /// # use rorm::fields::types::{ForeignModel, MaxStr};
+    /// # use rorm::internal::query_context::QueryContext;
+    /// # use rorm::prelude::*;
+    /// #[derive(Model)]
+    /// struct User {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///     name: MaxStr<255>,
+    /// }
+    ///
+    /// #[derive(Patch)]
+    /// #[rorm(model = "User")]
+    /// struct UserName {
+    ///     name: MaxStr<255>,
+    /// }
+    ///
+    /// #[derive(Model)]
+    /// struct Comment {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///     author: ForeignModel<User>,
+    /// }
+    ///
+    /// // Select each comment's id together with its author's name
+    /// // i.e. the result type would be `(i64, UserName)`
+    /// let selector = (Comment.id, Comment.author.query_as(UserName));
+    ///
+    /// let mut ctx = QueryContext::new();
+    /// selector.select(&mut ctx);
+    /// assert_eq!(ctx.get_joins().len(), 1);
+    /// let selects = ctx.get_selects();
+    /// assert_eq!(selects[1].column_name, "name");
+    /// assert_eq!(selects[1].table_name, Some(ctx.get_joins()[0].join_alias));
+    /// ```
     pub fn query_as<S>(self, selector: S) -> PathedSelector<S, <I::Path as Path>::Step<I::Field>>
     where
         S: Selector<Model = <F::ChildField as Field>::Model>,
@@ -176,6 +219,74 @@ impl<I: FieldProxyImpl> FieldProxy<I> {
         <FieldType!(I)>::field_greater_equals(self, rhs)
     }
 
+    /// Check if the field's value lies in the half-open range `[start, end)` using `>=` and `<`
+    ///
+    /// The range excludes its `end`, so it should be the first point in time which is not part of the range.
+    /// Unlike sql's `BETWEEN`, this way a range ending at midnight doesn't include the following day's first instant
+    /// and a range ending at a day's last instant doesn't miss the fractions of its last second.
+    ///
+    /// It works with `chrono`'s as well as `time`'s date and time types:
+    /// ```
+    /// # use rorm::prelude::*;
+    /// # use rorm::conditions::{Binary, BinaryOperator, Value};
+    /// # use rorm::conditions::collections::{CollectionOperator, StaticCollection};
+    /// # #[derive(Model)]
+    /// # struct Order {
+    /// #     #[rorm(id)]
+    /// #     id: i64,
+    /// #     chrono_placed_at: chrono::NaiveDateTime,
+    /// #     time_placed_at: time::PrimitiveDateTime,
+    /// # }
+    /// use chrono::NaiveDate;
+    /// use time::{Date, Month};
+    ///
+    /// // Every order placed in January 2024
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 2, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    /// let StaticCollection {
+    ///     operator: CollectionOperator::And,
+    ///     tuple: (
+    ///         Binary { operator: BinaryOperator::GreaterOrEquals, snd_arg: Value::ChronoNaiveDateTime(lower), .. },
+    ///         Binary { operator: BinaryOperator::Less, snd_arg: Value::ChronoNaiveDateTime(upper), .. },
+    ///     ),
+    /// } = Order.chrono_placed_at.in_date_range(start, end)
+    /// else {
+    ///     unreachable!();
+    /// };
+    /// assert_eq!((lower, upper), (start, end));
+    ///
+    /// let start = Date::from_calendar_date(2024, Month::January, 1).unwrap().midnight();
+    /// let end = Date::from_calendar_date(2024, Month::February, 1).unwrap().midnight();
+    /// let StaticCollection {
+    ///     operator: CollectionOperator::And,
+    ///     tuple: (
+    ///         Binary { operator: BinaryOperator::GreaterOrEquals, snd_arg: Value::TimePrimitiveDateTime(lower), .. },
+    ///         Binary { operator: BinaryOperator::Less, snd_arg: Value::TimePrimitiveDateTime(upper), .. },
+    ///     ),
+    /// } = Order.time_placed_at.in_date_range(start, end)
+    /// else {
+    ///     unreachable!();
+    /// };
+    /// assert_eq!((lower, upper), (start, end));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn in_date_range<'rhs, Rhs: 'rhs, Any>(
+        self,
+        start: Rhs,
+        end: Rhs,
+    ) -> StaticCollection<(
+        <FieldType!(I) as FieldOrd<'rhs, Rhs, Any>>::GeCond<I>,
+        <FieldType!(I) as FieldOrd<'rhs, Rhs, Any>>::LtCond<I>,
+    )>
+    where
+        FieldType!(I): FieldOrd<'rhs, Rhs, Any>,
+    {
+        StaticCollection {
+            operator: CollectionOperator::And,
+            tuple: (self.greater_equals(start), self.less_than(end)),
+        }
+    }
+
     /// Compare the field to another value using `LIKE`
     pub fn like<'rhs, Rhs: 'rhs, Any>(
         self,
@@ -264,6 +375,24 @@ impl<I: FieldProxyImpl> FieldProxy<I> {
 
     /// Returns the maximum value of all values in the group.
     /// If there are only null values in the group, this function will return null.
+    ///
+    /// The result is always an `Option`, because an empty group also produces null:
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # #[derive(Model)]
+    /// # struct User {
+    /// #     #[rorm(id)]
+    /// #     id: i64,
+    /// #     admin: bool,
+    /// # }
+    /// pub async fn highest_admin_id(db: &Database) -> Option<i64> {
+    ///     query(db, User.id.max())
+    ///         .condition(User.admin.equals(true))
+    ///         .one()
+    ///         .await
+    ///         .unwrap()
+    /// }
+    /// ```
     pub fn max(self) -> AggregatedColumn<I, <FieldType!(I) as FieldMax>::Result>
     where
         FieldType!(I): FieldMax,
@@ -278,6 +407,24 @@ impl<I: FieldProxyImpl> FieldProxy<I> {
 
     /// Returns the minimum value of all values in the group.
     /// If there are only null values in the group, this function will return null.
+    ///
+    /// The result is always an `Option`, because an empty group also produces null:
+    /// ```no_run
+    /// # use rorm::{Model, Database, query};
+    /// # #[derive(Model)]
+    /// # struct User {
+    /// #     #[rorm(id)]
+    /// #     id: i64,
+    /// #     admin: bool,
+    /// # }
+    /// pub async fn lowest_admin_id(db: &Database) -> Option<i64> {
+    ///     query(db, User.id.min())
+    ///         .condition(User.admin.equals(true))
+    ///         .one()
+    ///         .await
+    ///         .unwrap()
+    /// }
+    /// ```
     pub fn min(self) -> AggregatedColumn<I, <FieldType!(I) as FieldMin>::Result>
     where
         FieldType!(I): FieldMin,
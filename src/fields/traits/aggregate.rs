@@ -11,7 +11,44 @@ use crate::fields::traits::{Array, FieldType};
 
 /// Marker for [`FieldProxy::count`]
 ///
-/// This is implemented for every [`SingleColumnFieldType`]
+/// This is implemented for every [`FieldType`] which consists of a single column,
+/// since sql can count the non-null values of any column:
+/// ```
+/// # use rorm::prelude::*;
+/// # use rorm::fields::types::MaxStr;
+/// #[derive(Model)]
+/// struct Post {
+///     #[rorm(id)]
+///     id: i64,
+///     title: MaxStr<255>,
+///     published_at: Option<chrono::NaiveDateTime>,
+///     views: i32,
+/// }
+///
+/// let _ = Post.title.count();
+/// let _ = Post.published_at.count();
+/// let _ = Post.views.count();
+/// ```
+///
+/// Types without any column (i.e. [`BackRef`](crate::fields::types::BackRef)) can't be counted:
+/// ```compile_fail
+/// # use rorm::prelude::*;
+/// # use rorm::fields::types::{BackRef, ForeignModel};
+/// #[derive(Model)]
+/// struct User {
+///     #[rorm(id)]
+///     id: i64,
+///     posts: BackRef<field!(Post.author)>,
+/// }
+/// #[derive(Model)]
+/// struct Post {
+///     #[rorm(id)]
+///     id: i64,
+///     author: ForeignModel<User>,
+/// }
+///
+/// let _ = User.posts.count();
+/// ```
 pub trait FieldCount: FieldType {}
 impl<T> FieldCount for T where T: FieldType<Columns = Array<1>> {}
 
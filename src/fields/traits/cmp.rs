@@ -10,6 +10,80 @@
 //! ## Implementing
 //! - Each method takes an [`FieldAccess`]; an implementation may assume that the access' field's type
 //!   matches the type the trait is implemented on. This isn't enforced using trait bounds (yet?) to reduce complexity.
+//!
+//! ## Comparing two fields
+//! [`FieldEq`] and [`FieldOrd`] are also implemented for a [`FieldProxy`] of the same type.
+//! The other field may be on a related model, as long as it is reached through a relation path
+//! starting at the query's model. Both paths are joined when the condition is built:
+//! ```
+//! # use rorm::internal::query_context::QueryContext;
+//! # use rorm::fields::types::ForeignModel;
+//! # use rorm::prelude::*;
+//! # #[derive(Model)]
+//! # struct Thread {
+//! #     #[rorm(id)]
+//! #     id: i64,
+//! #     created_at: i64,
+//! # }
+//! # #[derive(Model)]
+//! # struct Comment {
+//! #     #[rorm(id)]
+//! #     id: i64,
+//! #     created_at: i64,
+//! #     thread: ForeignModel<Thread>,
+//! # }
+//! let mut ctx = QueryContext::new();
+//! ctx.add_condition(&Comment.created_at.greater_than(Comment.thread.created_at));
+//! assert_eq!(ctx.get_joins().len(), 1);
+//! ```
+//! Using `Thread.created_at` instead would refer to a table which is not part of the query.
+//! The query builders reject such a condition at compile time
+//! (see [`ConditionOrigin`](crate::conditions::ConditionOrigin)).
+//! A condition hidden in a `Box<dyn Condition>` can't be checked and results in an error from the database.
+//!
+//! ## Comparing dates with datetimes
+//! A naive datetime field (`chrono::NaiveDateTime` or `time::PrimitiveDateTime`)
+//! can also be compared with a date of the same crate.
+//! The date is expanded to its whole day i.e. the range from its midnight (inclusive) to the next day's midnight (exclusive):
+//! ```
+//! # use rorm::prelude::*;
+//! # use rorm::conditions::{Binary, BinaryOperator, Value};
+//! # use rorm::conditions::collections::{CollectionOperator, StaticCollection};
+//! # #[derive(Model)]
+//! # struct Order {
+//! #     #[rorm(id)]
+//! #     id: i64,
+//! #     placed_at: chrono::NaiveDateTime,
+//! # }
+//! use chrono::NaiveDate;
+//!
+//! let day = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+//! let start = day.and_hms_opt(0, 0, 0).unwrap();
+//! let end = NaiveDate::from_ymd_opt(2024, 1, 2).unwrap().and_hms_opt(0, 0, 0).unwrap();
+//!
+//! // Everything on 2024-01-01
+//! let StaticCollection {
+//!     operator: CollectionOperator::And,
+//!     tuple: (
+//!         Binary { operator: BinaryOperator::GreaterOrEquals, snd_arg: Value::ChronoNaiveDateTime(lower), .. },
+//!         Binary { operator: BinaryOperator::Less, snd_arg: Value::ChronoNaiveDateTime(upper), .. },
+//!     ),
+//! } = Order.placed_at.equals(day)
+//! else {
+//!     unreachable!();
+//! };
+//! assert_eq!((lower, upper), (start, end));
+//!
+//! // Everything after 2024-01-01
+//! let Binary { operator: BinaryOperator::GreaterOrEquals, snd_arg: Value::ChronoNaiveDateTime(lower), .. } =
+//!     Order.placed_at.greater_than(day)
+//! else {
+//!     unreachable!();
+//! };
+//! assert_eq!(lower, end);
+//! ```
+//! Timezone aware types (`DateTime<Utc>` and `OffsetDateTime`) don't support this,
+//! because a date doesn't specify in which timezone its day starts.
 
 use super::FieldType;
 use crate::conditions::{Binary, BinaryOperator, Column, Condition};
@@ -320,3 +394,158 @@ where
         }
     }
 }
+
+/// Implements [`FieldEq`] and [`FieldOrd`] between a datetime type and a date type.
+///
+/// The date is treated as the whole day i.e. the half-open range from its midnight to the next day's midnight.
+///
+/// It takes
+/// - the datetime type to implement on
+/// - the date type
+/// - a closure to convert the date into its day's range's bounds as [`Value`]s
+#[doc(hidden)]
+#[allow(non_snake_case)] // makes it clearer that a trait and which trait is meant
+#[macro_export]
+macro_rules! impl_FieldEq_FieldOrd_day {
+    ($lhs:ty, $rhs:ty, $into_bounds:expr) => {
+        impl<'rhs> $crate::fields::traits::cmp::FieldEq<'rhs, $rhs> for $lhs {
+            type EqCond<I: $crate::fields::proxy::FieldProxyImpl> =
+                $crate::conditions::StaticCollection<(
+                    $crate::conditions::Binary<
+                        $crate::conditions::Column<I>,
+                        $crate::conditions::Value<'rhs>,
+                    >,
+                    $crate::conditions::Binary<
+                        $crate::conditions::Column<I>,
+                        $crate::conditions::Value<'rhs>,
+                    >,
+                )>;
+            fn field_equals<I: $crate::fields::proxy::FieldProxyImpl>(
+                field: $crate::fields::proxy::FieldProxy<I>,
+                value: $rhs,
+            ) -> Self::EqCond<I> {
+                #[allow(clippy::redundant_closure_call)] // clean way to pass code to a macro
+                let (start, end) = $into_bounds(value);
+                $crate::conditions::StaticCollection {
+                    operator: $crate::conditions::collections::CollectionOperator::And,
+                    tuple: (
+                        $crate::conditions::Binary {
+                            operator: $crate::conditions::BinaryOperator::GreaterOrEquals,
+                            fst_arg: $crate::conditions::Column(field),
+                            snd_arg: start,
+                        },
+                        $crate::conditions::Binary {
+                            operator: $crate::conditions::BinaryOperator::Less,
+                            fst_arg: $crate::conditions::Column(field),
+                            snd_arg: end,
+                        },
+                    ),
+                }
+            }
+
+            type NeCond<I: $crate::fields::proxy::FieldProxyImpl> =
+                $crate::conditions::StaticCollection<(
+                    $crate::conditions::Binary<
+                        $crate::conditions::Column<I>,
+                        $crate::conditions::Value<'rhs>,
+                    >,
+                    $crate::conditions::Binary<
+                        $crate::conditions::Column<I>,
+                        $crate::conditions::Value<'rhs>,
+                    >,
+                )>;
+            fn field_not_equals<I: $crate::fields::proxy::FieldProxyImpl>(
+                field: $crate::fields::proxy::FieldProxy<I>,
+                value: $rhs,
+            ) -> Self::NeCond<I> {
+                #[allow(clippy::redundant_closure_call)] // clean way to pass code to a macro
+                let (start, end) = $into_bounds(value);
+                $crate::conditions::StaticCollection {
+                    operator: $crate::conditions::collections::CollectionOperator::Or,
+                    tuple: (
+                        $crate::conditions::Binary {
+                            operator: $crate::conditions::BinaryOperator::Less,
+                            fst_arg: $crate::conditions::Column(field),
+                            snd_arg: start,
+                        },
+                        $crate::conditions::Binary {
+                            operator: $crate::conditions::BinaryOperator::GreaterOrEquals,
+                            fst_arg: $crate::conditions::Column(field),
+                            snd_arg: end,
+                        },
+                    ),
+                }
+            }
+        }
+
+        impl<'rhs> $crate::fields::traits::cmp::FieldOrd<'rhs, $rhs> for $lhs {
+            type LtCond<I: $crate::fields::proxy::FieldProxyImpl> = $crate::conditions::Binary<
+                $crate::conditions::Column<I>,
+                $crate::conditions::Value<'rhs>,
+            >;
+            fn field_less_than<I: $crate::fields::proxy::FieldProxyImpl>(
+                field: $crate::fields::proxy::FieldProxy<I>,
+                value: $rhs,
+            ) -> Self::LtCond<I> {
+                #[allow(clippy::redundant_closure_call)] // clean way to pass code to a macro
+                let (start, _) = $into_bounds(value);
+                $crate::conditions::Binary {
+                    operator: $crate::conditions::BinaryOperator::Less,
+                    fst_arg: $crate::conditions::Column(field),
+                    snd_arg: start,
+                }
+            }
+
+            type LeCond<I: $crate::fields::proxy::FieldProxyImpl> = $crate::conditions::Binary<
+                $crate::conditions::Column<I>,
+                $crate::conditions::Value<'rhs>,
+            >;
+            fn field_less_equals<I: $crate::fields::proxy::FieldProxyImpl>(
+                field: $crate::fields::proxy::FieldProxy<I>,
+                value: $rhs,
+            ) -> Self::LeCond<I> {
+                #[allow(clippy::redundant_closure_call)] // clean way to pass code to a macro
+                let (_, end) = $into_bounds(value);
+                $crate::conditions::Binary {
+                    operator: $crate::conditions::BinaryOperator::Less,
+                    fst_arg: $crate::conditions::Column(field),
+                    snd_arg: end,
+                }
+            }
+
+            type GtCond<I: $crate::fields::proxy::FieldProxyImpl> = $crate::conditions::Binary<
+                $crate::conditions::Column<I>,
+                $crate::conditions::Value<'rhs>,
+            >;
+            fn field_greater_than<I: $crate::fields::proxy::FieldProxyImpl>(
+                field: $crate::fields::proxy::FieldProxy<I>,
+                value: $rhs,
+            ) -> Self::GtCond<I> {
+                #[allow(clippy::redundant_closure_call)] // clean way to pass code to a macro
+                let (_, end) = $into_bounds(value);
+                $crate::conditions::Binary {
+                    operator: $crate::conditions::BinaryOperator::GreaterOrEquals,
+                    fst_arg: $crate::conditions::Column(field),
+                    snd_arg: end,
+                }
+            }
+
+            type GeCond<I: $crate::fields::proxy::FieldProxyImpl> = $crate::conditions::Binary<
+                $crate::conditions::Column<I>,
+                $crate::conditions::Value<'rhs>,
+            >;
+            fn field_greater_equals<I: $crate::fields::proxy::FieldProxyImpl>(
+                field: $crate::fields::proxy::FieldProxy<I>,
+                value: $rhs,
+            ) -> Self::GeCond<I> {
+                #[allow(clippy::redundant_closure_call)] // clean way to pass code to a macro
+                let (start, _) = $into_bounds(value);
+                $crate::conditions::Binary {
+                    operator: $crate::conditions::BinaryOperator::GreaterOrEquals,
+                    fst_arg: $crate::conditions::Column(field),
+                    snd_arg: start,
+                }
+            }
+        }
+    };
+}
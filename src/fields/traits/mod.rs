@@ -7,7 +7,7 @@ use rorm_db::Row;
 pub use self::aggregate::*;
 pub use self::cmp::*;
 use crate::conditions::Value;
-use crate::crud::decoder::Decoder;
+use crate::crud::decoder::{is_null, Decoder};
 use crate::fields::proxy;
 use crate::fields::proxy::{FieldProxy, FieldProxyImpl};
 use crate::fields::utils::const_fn::ConstFn;
@@ -126,33 +126,58 @@ impl<T: FieldType> FieldType for Option<T> {
 }
 
 /// [`FieldDecoder`] for [`Option<T>`]
-pub struct OptionDecoder<T: FieldType>(T::Decoder);
+///
+/// The result is `None` if all of `T`'s columns are `NULL`.
+/// This is checked explicitly before decoding `T`,
+/// because some databases (i.e. SQLite) decode a `NULL` as `0` or `""`
+/// instead of reporting it as [`RowError::UnexpectedNull`].
+pub struct OptionDecoder<T: FieldType> {
+    decoder: T::Decoder,
+    columns: Vec<(usize, String)>,
+}
 impl<T: FieldType> FieldDecoder for OptionDecoder<T> {
     fn new<I>(ctx: &mut QueryContext, _: FieldProxy<I>) -> Self
     where
         I: FieldProxyImpl<Field: Field<Type = Self::Result>>,
     {
-        Self(T::Decoder::new::<(FakeField<T, I::Field>, I::Path)>(
-            ctx,
-            proxy::new(),
-        ))
+        let (decoder, columns) = ctx.collect_selects(|ctx| {
+            T::Decoder::new::<(FakeField<T, I::Field>, I::Path)>(ctx, proxy::new())
+        });
+        Self { decoder, columns }
     }
 }
 impl<T: FieldType> Decoder for OptionDecoder<T> {
     type Result = Option<T>;
 
     fn by_name<'index>(&'index self, row: &'_ Row) -> Result<Self::Result, RowError<'index>> {
-        self.0.by_name(row).map(Some).or_else(|error| match error {
-            RowError::UnexpectedNull { .. } => Ok(None),
-            _ => Err(error),
-        })
+        if !self.columns.is_empty()
+            && self
+                .columns
+                .iter()
+                .all(|(_, column)| is_null(row, column.as_str()))
+        {
+            return Ok(None);
+        }
+        self.decoder
+            .by_name(row)
+            .map(Some)
+            .or_else(|error| match error {
+                RowError::UnexpectedNull { .. } => Ok(None),
+                _ => Err(error),
+            })
     }
 
     fn by_index<'index>(&'index self, row: &'_ Row) -> Result<Self::Result, RowError<'index>> {
-        self.0.by_index(row).map(Some).or_else(|error| match error {
-            RowError::UnexpectedNull { .. } => Ok(None),
-            _ => Err(error),
-        })
+        if !self.columns.is_empty() && self.columns.iter().all(|&(index, _)| is_null(row, index)) {
+            return Ok(None);
+        }
+        self.decoder
+            .by_index(row)
+            .map(Some)
+            .or_else(|error| match error {
+                RowError::UnexpectedNull { .. } => Ok(None),
+                _ => Err(error),
+            })
     }
 }
 
@@ -165,6 +190,10 @@ impl<T: FieldType> Decoder for OptionDecoder<T> {
 ///     - `into_value` is used to convert `RustType` into a [`Value<'static>`] (must implement `Fn(RustType) -> Value<'static>`).
 ///     - `as_value` is used to convert `&'a RustType` into a [`Value<'a>`] (must implement `Fn(&'_ RustType) -> Value<'_>`).
 ///       If `RustType` implements `Copy`, `as_value` can be omitted and will use `into_value` instead.
+/// - `impl_FieldType!(RustType, NullType, into_value, as_value, Check, GetAnnotations);`
+///     - `Check` and `GetAnnotations` override [`FieldType::Check`] and [`FieldType::GetAnnotations`].
+///       `Check` defaults to [`shared_linter_check`](crate::fields::utils::check::shared_linter_check)
+///       and `GetAnnotations` to [`forward_annotations`](crate::fields::utils::get_annotations::forward_annotations).
 #[doc(hidden)]
 #[allow(non_snake_case)] // makes it clearer that a trait and which trait is meant
 #[macro_export]
@@ -182,6 +211,16 @@ macro_rules! impl_FieldType {
         );
     };
     ($type:ty, $null_type:ident, $into_value:expr, $as_value:expr, $Check:ty) => {
+        impl_FieldType!(
+            $type,
+            $null_type,
+            $into_value,
+            $as_value,
+            $Check,
+            $crate::fields::utils::get_annotations::forward_annotations<1>
+        );
+    };
+    ($type:ty, $null_type:ident, $into_value:expr, $as_value:expr, $Check:ty, $GetAnnotations:ty) => {
         impl $crate::fields::traits::FieldType for $type {
             type Columns = $crate::fields::traits::Array<1>;
 
@@ -207,7 +246,7 @@ macro_rules! impl_FieldType {
 
             type Decoder = $crate::crud::decoder::DirectDecoder<Self>;
 
-            type GetAnnotations = $crate::fields::utils::get_annotations::forward_annotations<1>;
+            type GetAnnotations = $GetAnnotations;
 
             type Check = $Check;
 
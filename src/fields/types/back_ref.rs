@@ -2,7 +2,7 @@
 
 use std::collections::HashMap;
 use std::fmt;
-use std::future::poll_fn;
+use std::future::{poll_fn, Future};
 use std::pin::pin;
 
 use futures_core::Stream;
@@ -11,7 +11,9 @@ use rorm_db::sql::value::NullType;
 use rorm_db::Error;
 
 use crate::conditions::collections::CollectionOperator::Or;
-use crate::conditions::{Binary, BinaryOperator, Column, Condition, DynamicCollection, Value};
+use crate::conditions::{
+    Binary, BinaryOperator, Column, Condition, ConditionOrigin, DynamicCollection, Value,
+};
 use crate::crud::decoder::NoopDecoder;
 use crate::crud::query::query;
 use crate::fields::proxy;
@@ -27,6 +29,49 @@ use crate::model::GetField;
 use crate::Patch;
 
 /// A back reference is the other direction to a [foreign model](crate::prelude::ForeignModelByField)
+///
+/// ## Many to many
+/// A many to many relation is modelled using a junction model with two foreign models.
+/// A back reference to the junction model can then be followed by the foreign model on the other side,
+/// which joins both the junction and the related table:
+/// ```
+/// # use rorm::internal::query_context::QueryContext;
+/// use rorm::fields::types::{BackRef, ForeignModel};
+/// use rorm::prelude::*;
+///
+/// #[derive(Model)]
+/// struct Thread {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     tags: BackRef<field!(ThreadTag.thread)>,
+/// }
+///
+/// #[derive(Model)]
+/// struct Tag {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     #[rorm(max_length = 255)]
+///     name: String,
+/// }
+///
+/// #[derive(Model)]
+/// struct ThreadTag {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     thread: ForeignModel<Thread>,
+///
+///     tag: ForeignModel<Tag>,
+/// }
+///
+/// // All threads tagged with "rust"
+/// let condition = Thread.tags.tag.name.equals("rust");
+/// # let mut ctx = QueryContext::new();
+/// # ctx.add_condition(&condition);
+/// # assert_eq!(ctx.get_joins().len(), 2);
+/// ```
 #[derive(Clone)]
 pub struct BackRef<FMF: ForeignModelField> {
     /// Cached list of models referencing this one.
@@ -77,7 +122,7 @@ where
     FMF::Model: GetField<FMF>, // always true
     foreign_model::RF<FMF>: SingleColumnField,
 {
-    fn model_as_condition<BRP>(patch: &BRP) -> impl Condition
+    fn model_as_condition<BRP>(patch: &BRP) -> impl Condition + ConditionOrigin<FMF::Model>
     where
         BRP: Patch<Model = BRF::Model>,
         BRP: GetField<foreign_model::RF<FMF>>,
@@ -89,6 +134,47 @@ where
         }
     }
 
+    /// Query the models referencing a patch without touching its [`BackRef`]'s cache.
+    ///
+    /// The patch is only borrowed while creating the future,
+    /// so it can be awaited without holding on to the patch.
+    /// (To cache the result, simply store it in the patch's [`BackRef::cached`].)
+    ///
+    /// ```no_run
+    /// # use rorm::{Database, Model};
+    /// # use rorm::fields::types::{BackRef, ForeignModel};
+    /// # use rorm::field;
+    /// # #[derive(Model)] pub struct Thread { #[rorm(id)] id: i64, posts: BackRef<field!(Post.thread)> }
+    /// # #[derive(Model)] pub struct Post { #[rorm(id)] id: i64, thread: ForeignModel<Thread> }
+    /// pub async fn get_posts(db: &Database, thread: &Thread) -> Vec<Post> {
+    ///     Thread.posts.load(db, thread).await.unwrap()
+    /// }
+    /// ```
+    pub fn load<'ex, BRP>(
+        &self,
+        executor: impl Executor<'ex>,
+        patch: &BRP,
+    ) -> impl Future<Output = Result<Vec<FMF::Model>, Error>>
+    where
+        <foreign_model::RF<FMF> as Field>::Type: Clone,
+        BRP: Patch<Model = BRF::Model>,
+        BRP: GetField<foreign_model::RF<FMF>>,
+    {
+        let condition = Binary {
+            operator: BinaryOperator::Equals,
+            fst_arg: Column(proxy::new::<(FMF, FMF::Model)>()),
+            snd_arg: foreign_model::RF::<FMF>::type_into_value(
+                <BRP as GetField<foreign_model::RF<FMF>>>::borrow_field(patch).clone(),
+            ),
+        };
+        async move {
+            query(executor, <FMF::Model as Patch>::ValueSpaceImpl::default())
+                .condition(condition)
+                .all()
+                .await
+        }
+    }
+
     /// Returns a reference to the [`BackRef`]'s cache after populating it if not done already.
     pub async fn get_or_query<'p, BRP>(
         &self,
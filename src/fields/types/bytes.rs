@@ -0,0 +1,64 @@
+//! [`FieldType`] for [`Bytes`]
+//!
+//! Unlike `Vec<u8>`, a [`Bytes`] can be cheaply cloned and sliced,
+//! which avoids copying large binary payloads when passing them around.
+//! Using it as a condition's or insert's value borrows it without copying it either:
+//! ```
+//! use bytes::Bytes;
+//! use rorm::conditions::Value;
+//! use rorm::fields::traits::FieldType;
+//!
+//! let payload = Bytes::from_static(b"some large payload");
+//! let [Value::Binary(value)] = payload.as_values() else {
+//!     unreachable!();
+//! };
+//! assert_eq!(value.as_ptr(), payload.as_ptr());
+//! ```
+
+use std::borrow::Cow;
+
+use bytes::Bytes;
+use rorm_db::sql::value::NullType;
+
+use crate::conditions::Value;
+use crate::fields::traits::{Array, FieldColumns, FieldType};
+use crate::fields::utils::check::binary_check;
+use crate::fields::utils::get_annotations::forward_annotations;
+use crate::fields::utils::get_names::single_column_name;
+use crate::{impl_FieldEq, new_converting_decoder};
+
+impl_FieldEq!(impl<'rhs> FieldEq<'rhs, &'rhs Bytes> for Bytes {|bytes: &'rhs Bytes| Value::Binary(Cow::Borrowed(bytes.as_ref()))});
+impl_FieldEq!(impl<'rhs> FieldEq<'rhs, &'rhs [u8]> for Bytes {|bytes: &'rhs [u8]| Value::Binary(Cow::Borrowed(bytes))});
+impl_FieldEq!(impl<'rhs> FieldEq<'rhs, Bytes> for Bytes {|bytes: Bytes| Value::Binary(Cow::Owned(bytes.into()))});
+impl_FieldEq!(impl<'rhs> FieldEq<'rhs, Option<&'rhs Bytes>> for Option<Bytes> {|option: Option<&'rhs Bytes>| option.map(|bytes| Value::Binary(Cow::Borrowed(bytes.as_ref()))).unwrap_or(Value::Null(NullType::Binary))});
+impl_FieldEq!(impl<'rhs> FieldEq<'rhs, Option<&'rhs [u8]>> for Option<Bytes> {|option: Option<&'rhs [u8]>| option.map(|bytes| Value::Binary(Cow::Borrowed(bytes))).unwrap_or(Value::Null(NullType::Binary))});
+impl_FieldEq!(impl<'rhs> FieldEq<'rhs, Option<Bytes>> for Option<Bytes> {|option: Option<Bytes>| option.map(|bytes| Value::Binary(Cow::Owned(bytes.into()))).unwrap_or(Value::Null(NullType::Binary))});
+
+impl FieldType for Bytes {
+    type Columns = Array<1>;
+
+    const NULL: FieldColumns<Self, NullType> = [NullType::Binary];
+
+    fn into_values<'a>(self) -> FieldColumns<Self, Value<'a>> {
+        [Value::Binary(Cow::Owned(self.into()))]
+    }
+
+    #[inline(always)]
+    fn as_values(&self) -> FieldColumns<Self, Value<'_>> {
+        [Value::Binary(Cow::Borrowed(self.as_ref()))]
+    }
+
+    type Decoder = BytesDecoder;
+
+    type GetAnnotations = forward_annotations<1>;
+
+    type Check = binary_check;
+
+    type GetNames = single_column_name;
+}
+new_converting_decoder!(
+    pub BytesDecoder,
+    |value: Vec<u8>| -> Bytes {
+        Ok::<_, String>(Bytes::from(value))
+    }
+);
@@ -2,7 +2,9 @@ use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use rorm_db::sql::value::NullType;
 
 use crate::conditions::Value;
-use crate::{impl_FieldEq, impl_FieldMin_FieldMax, impl_FieldOrd, impl_FieldType};
+use crate::{
+    impl_FieldEq, impl_FieldEq_FieldOrd_day, impl_FieldMin_FieldMax, impl_FieldOrd, impl_FieldType,
+};
 
 impl_FieldType!(NaiveTime, ChronoNaiveTime, Value::ChronoNaiveTime);
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, NaiveTime> for NaiveTime { Value::ChronoNaiveTime });
@@ -38,6 +40,19 @@ impl_FieldOrd!(
         .unwrap_or(Value::Null(NullType::ChronoNaiveDateTime))
 );
 impl_FieldMin_FieldMax!(NaiveDateTime);
+// A date is compared as its whole day
+// (`DateTime<Utc>` doesn't get this, because a date doesn't know its timezone)
+impl_FieldEq_FieldOrd_day!(NaiveDateTime, NaiveDate, |date: NaiveDate| {
+    let start = date.and_hms_opt(0, 0, 0).unwrap();
+    let end = date
+        .succ_opt()
+        .map(|next| next.and_hms_opt(0, 0, 0).unwrap())
+        .unwrap_or(NaiveDateTime::MAX);
+    (
+        Value::ChronoNaiveDateTime(start),
+        Value::ChronoNaiveDateTime(end),
+    )
+});
 
 impl_FieldType!(DateTime<Utc>, ChronoDateTime, Value::ChronoDateTime);
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, DateTime<Utc>> for DateTime<Utc> { Value::ChronoDateTime });
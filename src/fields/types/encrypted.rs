@@ -0,0 +1,169 @@
+//! The [`Encrypted<T, C>`] wrapper to store data encrypted in the db
+
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use rorm_db::sql::value::NullType;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::conditions::Value;
+use crate::fields::traits::{Array, FieldColumns, FieldType};
+use crate::fields::utils::check::shared_linter_check;
+use crate::fields::utils::get_annotations::forward_annotations;
+use crate::fields::utils::get_names::single_column_name;
+use crate::new_converting_decoder;
+
+/// A cipher used by [`Encrypted`] to encrypt and decrypt its data.
+///
+/// The methods don't take any key or `self`,
+/// the implementor is responsible for retrieving its key (for example from a `static` set during startup).
+pub trait Cipher: 'static {
+    /// Encrypt some plaintext
+    fn encrypt(plaintext: &[u8]) -> Vec<u8>;
+
+    /// Decrypt some ciphertext previously produced by [`Cipher::encrypt`]
+    fn decrypt(ciphertext: &[u8]) -> Result<Vec<u8>, String>;
+}
+
+/// Stores data by serializing it to json and encrypting it using the cipher `C`.
+///
+/// The encrypted bytes are meaningless to the database.
+/// Therefore, `Encrypted` doesn't implement any of the comparison traits
+/// and conditions on encrypted fields are rejected at compile time.
+///
+/// ```
+/// use rorm::fields::traits::FieldType;
+/// use rorm::fields::types::{Cipher, Encrypted};
+/// use rorm::conditions::Value;
+///
+/// /// Don't use this outside of examples :)
+/// struct Xor;
+/// impl Cipher for Xor {
+///     fn encrypt(plaintext: &[u8]) -> Vec<u8> {
+///         plaintext.iter().map(|byte| byte ^ 0x2a).collect()
+///     }
+///     fn decrypt(ciphertext: &[u8]) -> Result<Vec<u8>, String> {
+///         Ok(Self::encrypt(ciphertext))
+///     }
+/// }
+///
+/// let email = Encrypted::<String, Xor>::new("alice@example.com".to_string());
+/// let [Value::Binary(ciphertext)] = email.as_values() else {
+///     unreachable!();
+/// };
+/// assert_ne!(ciphertext.as_ref(), b"\"alice@example.com\"");
+///
+/// let decrypted = Encrypted::<String, Xor>::decrypt(&ciphertext).unwrap();
+/// assert_eq!(decrypted.into_inner(), "alice@example.com");
+/// ```
+pub struct Encrypted<T: Serialize + DeserializeOwned, C: Cipher> {
+    value: T,
+    cipher: PhantomData<C>,
+}
+
+impl<T: Serialize + DeserializeOwned, C: Cipher> Encrypted<T, C> {
+    /// Wrap a value
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            cipher: PhantomData,
+        }
+    }
+
+    /// Unwrap into inner T value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Serialize and encrypt the value
+    ///
+    /// This is what gets stored in the database.
+    pub fn encrypt(&self) -> Vec<u8> {
+        // TODO propagate error?
+        C::encrypt(&serde_json::to_vec(&self.value).unwrap())
+    }
+
+    /// Decrypt and deserialize a value previously produced by [`Encrypted::encrypt`]
+    pub fn decrypt(ciphertext: &[u8]) -> Result<Self, String> {
+        let plaintext = C::decrypt(ciphertext)?;
+        serde_json::from_slice(&plaintext)
+            .map(Self::new)
+            .map_err(|err| format!("Couldn't decode decrypted json: {err}"))
+    }
+}
+
+new_converting_decoder!(
+    pub EncryptedDecoder<T: Serialize + DeserializeOwned, C: Cipher>,
+    |value: Vec<u8>| -> Encrypted<T, C> {
+        Encrypted::decrypt(&value)
+    }
+);
+impl<T: Serialize + DeserializeOwned + 'static, C: Cipher> FieldType for Encrypted<T, C> {
+    type Columns = Array<1>;
+
+    const NULL: FieldColumns<Self, NullType> = [NullType::Binary];
+
+    fn into_values<'a>(self) -> FieldColumns<Self, Value<'a>> {
+        [Value::Binary(Cow::Owned(self.encrypt()))]
+    }
+
+    fn as_values(&self) -> FieldColumns<Self, Value<'_>> {
+        [Value::Binary(Cow::Owned(self.encrypt()))]
+    }
+
+    type Decoder = EncryptedDecoder<T, C>;
+
+    type GetAnnotations = forward_annotations<1>;
+
+    type Check = shared_linter_check<1>;
+
+    type GetNames = single_column_name;
+}
+
+// Manual impls to avoid bounds on `C`
+impl<T: Serialize + DeserializeOwned + Clone, C: Cipher> Clone for Encrypted<T, C> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+impl<T: Serialize + DeserializeOwned + fmt::Debug, C: Cipher> fmt::Debug for Encrypted<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Encrypted").field(&self.value).finish()
+    }
+}
+
+// From
+impl<T: Serialize + DeserializeOwned, C: Cipher> From<T> for Encrypted<T, C> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+// Deref
+impl<T: Serialize + DeserializeOwned, C: Cipher> Deref for Encrypted<T, C> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+impl<T: Serialize + DeserializeOwned, C: Cipher> DerefMut for Encrypted<T, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+// AsRef
+impl<T: Serialize + DeserializeOwned, C: Cipher> AsRef<T> for Encrypted<T, C> {
+    fn as_ref(&self) -> &T {
+        &self.value
+    }
+}
+impl<T: Serialize + DeserializeOwned, C: Cipher> AsMut<T> for Encrypted<T, C> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
@@ -17,6 +17,10 @@ pub type ForeignModel<M> = ForeignModelByField<<M as Model>::Primary>;
 /// Stores a link to another model in a field.
 ///
 /// In database language, this is a many to one relation.
+///
+/// Annotating the field with `#[rorm(foreign(no_constraint))]` keeps the relation
+/// (i.e. joins and [`query`](ForeignModelByField::query) still work)
+/// but omits the foreign key constraint from the database.
 pub struct ForeignModelByField<FF: SingleColumnField>(pub FF::Type);
 
 impl<FF: SingleColumnField> ForeignModelByField<FF> {
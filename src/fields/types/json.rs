@@ -1,6 +1,7 @@
 //! The [`Json<T>`] wrapper to store json data in the db
 
 use std::borrow::Cow;
+use std::mem;
 use std::ops::{Deref, DerefMut};
 
 use rorm_db::sql::value::NullType;
@@ -12,12 +13,28 @@ use crate::fields::traits::{Array, FieldColumns, FieldType};
 use crate::fields::utils::check::shared_linter_check;
 use crate::fields::utils::get_annotations::forward_annotations;
 use crate::fields::utils::get_names::single_column_name;
-use crate::new_converting_decoder;
+use crate::{impl_FieldEq, new_converting_decoder};
 
 /// Stores data by serializing it to json.
 ///
 /// This is just a convenience wrapper around [serde_json] and `Vec<u8>`.
 ///
+/// The json is written with its objects' keys in sorted order.
+/// This makes the stored bytes independent of the order `T` serializes its keys in
+/// and allows comparing a column to a value using [`FieldProxy::equals`](crate::fields::proxy::FieldProxy::equals).
+/// (Rows written by rorm versions before this canonicalization may contain their keys in a different order.
+/// They only compare equal after being written again.)
+/// Sorting the keys requires serializing `T` into an intermediate [`serde_json::Value`] first,
+/// which costs an additional allocation per object and array for every write and comparison.
+///
+/// If `T` fails to serialize (for example a map whose keys aren't strings),
+/// the insert, update or query using it returns the error instead of executing.
+///
+/// Since it doesn't rely on any database specific type,
+/// `Json<Vec<T>>` is the portable alternative to postgres' arrays (for example to store a list of tags).
+/// Querying for rows whose list contains a specific element is not supported (yet),
+/// because the json is stored as opaque bytes.
+///
 /// ```no_run
 /// # use std::collections::HashMap;
 /// use rorm::Model;
@@ -29,6 +46,8 @@ use crate::new_converting_decoder;
 ///     pub id: i64,
 ///
 ///     pub data: Json<HashMap<String, String>>,
+///
+///     pub tags: Json<Vec<String>>,
 /// }
 /// ```
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -55,15 +74,11 @@ impl<T: Serialize + DeserializeOwned + 'static> FieldType for Json<T> {
     const NULL: FieldColumns<Self, NullType> = [NullType::Binary];
 
     fn into_values<'a>(self) -> FieldColumns<Self, Value<'a>> {
-        [Value::Binary(Cow::Owned(
-            serde_json::to_vec(&self.0).unwrap(),
-        ))] // TODO propagate error?
+        [conv_json(&self)]
     }
 
     fn as_values(&self) -> FieldColumns<Self, Value<'_>> {
-        [Value::Binary(Cow::Owned(
-            serde_json::to_vec(&self.0).unwrap(),
-        ))] // TODO propagate error?
+        [conv_json(self)]
     }
 
     type Decoder = JsonDecoder<T>;
@@ -88,6 +103,45 @@ new_converting_decoder!(
     }
 );
 
+impl_FieldEq!(impl<'rhs, T> FieldEq<'rhs, &'rhs Json<T>> for Json<T> where T: Serialize, T: DeserializeOwned, { conv_json });
+impl_FieldEq!(impl<'rhs, T> FieldEq<'rhs, Json<T>> for Json<T> where T: Serialize, T: DeserializeOwned, { |json: Json<T>| conv_json(&json) });
+impl_FieldEq!(impl<'rhs, T> FieldEq<'rhs, Option<&'rhs Json<T>>> for Option<Json<T>> where T: Serialize, T: DeserializeOwned, { |option: Option<_>| option.map(conv_json).unwrap_or(Value::Null(NullType::Binary)) });
+impl_FieldEq!(impl<'rhs, T> FieldEq<'rhs, Option<Json<T>>> for Option<Json<T>> where T: Serialize, T: DeserializeOwned, { |option: Option<Json<T>>| option.as_ref().map(conv_json).unwrap_or(Value::Null(NullType::Binary)) });
+
+/// Serializes a json value with sorted object keys
+///
+/// If `T` fails to serialize, the error is returned as [`Value::Invalid`].
+fn conv_json<'a, T: Serialize + DeserializeOwned>(json: &Json<T>) -> Value<'a> {
+    let result = serde_json::to_value(&json.0).and_then(|mut value| {
+        sort_keys(&mut value);
+        serde_json::to_vec(&value)
+    });
+    match result {
+        Ok(bytes) => Value::Binary(Cow::Owned(bytes)),
+        Err(error) => Value::Invalid(Cow::Owned(format!(
+            "the value couldn't be serialized to json: {error}"
+        ))),
+    }
+}
+
+/// Recursively sorts all objects' keys
+///
+/// `serde_json::Map` is already sorted, unless serde_json's "preserve_order" feature is enabled somewhere.
+fn sort_keys(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Array(array) => array.iter_mut().for_each(sort_keys),
+        serde_json::Value::Object(object) => {
+            let mut entries: Vec<_> = mem::take(object).into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (key, mut value) in entries {
+                sort_keys(&mut value);
+                object.insert(key, value);
+            }
+        }
+        _ => {}
+    }
+}
+
 // From
 impl<T: Serialize + DeserializeOwned> From<T> for Json<T> {
     fn from(value: T) -> Self {
@@ -120,3 +174,55 @@ impl<T: Serialize + DeserializeOwned> AsMut<T> for Json<T> {
         &mut self.0
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Json;
+    use crate::conditions::Value;
+    use crate::fields::traits::FieldType;
+
+    /// Map which serializes its entries in insertion order
+    struct Ordered(Vec<(&'static str, i32)>);
+    impl Serialize for Ordered {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_map(self.0.iter().copied())
+        }
+    }
+    impl<'de> Deserialize<'de> for Ordered {
+        fn deserialize<D: Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+            unimplemented!("only serialized in this test")
+        }
+    }
+
+    #[test]
+    fn key_order_is_canonical() {
+        let [ab] = Json(Ordered(vec![("a", 1), ("b", 2)])).into_values();
+        let [ba] = Json(Ordered(vec![("b", 2), ("a", 1)])).into_values();
+        assert_eq!(ab, ba);
+        assert_eq!(ab, Value::Binary(br#"{"a":1,"b":2}"#.to_vec().into()));
+
+        let [nested] = Json(BTreeMap::from([(
+            "outer".to_string(),
+            Ordered(vec![("y", 1), ("x", 2)]),
+        )]))
+        .into_values();
+        assert_eq!(
+            nested,
+            Value::Binary(br#"{"outer":{"x":2,"y":1}}"#.to_vec().into())
+        );
+    }
+
+    #[test]
+    fn serialization_error_is_invalid() {
+        let [value] = Json(BTreeMap::from([((1, 2), 3)])).into_values();
+        let Value::Invalid(message) = &value else {
+            panic!("expected an invalid value, got {value:?}");
+        };
+        assert!(message.contains("key must be a string"), "{message}");
+        assert!(value.try_as_sql().is_err());
+    }
+}
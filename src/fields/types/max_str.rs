@@ -349,18 +349,26 @@ fn conv_opt_string<'a>(value: Option<impl Into<Cow<'a, str>>>) -> Value<'a> {
 
 #[cfg(feature = "utoipa")]
 mod utoipa_impl {
-    use utoipa::openapi::{Object, RefOr, Schema, SchemaType};
-    use utoipa::ToSchema;
+    use utoipa::openapi::{ObjectBuilder, RefOr, Schema, SchemaType};
+    use utoipa::{PartialSchema, ToSchema};
 
     use crate::fields::types::max_str_impl::LenImpl;
     use crate::fields::types::MaxStr;
 
     impl<'s, const MAX_LEN: usize, Impl: LenImpl> ToSchema<'s> for MaxStr<MAX_LEN, Impl, String> {
         fn schema() -> (&'s str, RefOr<Schema>) {
-            (
-                "MaxStr",
-                RefOr::T(Schema::Object(Object::with_type(SchemaType::String))),
-            )
+            ("MaxStr", <Self as PartialSchema>::schema())
+        }
+    }
+
+    impl<const MAX_LEN: usize, Impl: LenImpl> PartialSchema for MaxStr<MAX_LEN, Impl, String> {
+        fn schema() -> RefOr<Schema> {
+            RefOr::T(Schema::Object(
+                ObjectBuilder::new()
+                    .schema_type(SchemaType::String)
+                    .max_length(Some(MAX_LEN))
+                    .build(),
+            ))
         }
     }
 }
@@ -3,8 +3,11 @@
 //! See [`rorm::fields`](crate::fields) for full list of supported field types
 
 mod back_ref;
+#[cfg(feature = "bytes")]
+mod bytes;
 #[cfg(feature = "chrono")]
 mod chrono;
+mod encrypted;
 mod foreign_model;
 mod json;
 mod max_str;
@@ -14,6 +17,8 @@ mod msgpack;
 #[cfg(feature = "postgres-only")]
 pub(crate) mod postgres_only;
 mod std;
+mod std_net;
+mod std_path;
 #[cfg(feature = "time")]
 mod time;
 #[cfg(feature = "url")]
@@ -22,6 +27,7 @@ mod url;
 mod uuid;
 
 pub use back_ref::BackRef;
+pub use encrypted::{Cipher, Encrypted};
 pub use foreign_model::{ForeignModel, ForeignModelByField};
 pub use json::Json;
 pub use max_str::MaxStr;
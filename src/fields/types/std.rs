@@ -1,12 +1,123 @@
 use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+
+use rorm_db::row::RowError;
+use rorm_db::Row;
 
 use crate::conditions::Value;
+use crate::crud::decoder::Decoder;
 use crate::db::sql::value::NullType;
+use crate::fields::proxy::{FieldProxy, FieldProxyImpl};
+use crate::fields::traits::{Array, FieldColumns, FieldType};
+use crate::fields::utils::check::{binary_check, shared_linter_check};
+use crate::fields::utils::get_annotations::{forward_annotations, string_annotations};
+use crate::fields::utils::get_names::single_column_name;
+use crate::internal::field::decoder::FieldDecoder;
+use crate::internal::field::Field;
+use crate::internal::query_context::QueryContext;
 use crate::{
     impl_FieldEq, impl_FieldMin_FieldMax, impl_FieldOrd, impl_FieldSum_FieldAvg, impl_FieldType,
 };
 
-impl_FieldType!(bool, Bool, Value::Bool);
+impl FieldType for bool {
+    type Columns = Array<1>;
+
+    const NULL: FieldColumns<Self, NullType> = [NullType::Bool];
+
+    fn into_values<'a>(self) -> FieldColumns<Self, Value<'a>> {
+        [Value::Bool(self)]
+    }
+
+    fn as_values(&self) -> FieldColumns<Self, Value<'_>> {
+        [Value::Bool(*self)]
+    }
+
+    type Decoder = BoolDecoder;
+
+    type GetAnnotations = forward_annotations<1>;
+
+    type Check = shared_linter_check<1>;
+
+    type GetNames = single_column_name;
+}
+
+/// Decodes a `bool` from a row, falling back to integers of any size
+macro_rules! decode_bool {
+    ($row:expr, $index:expr) => {
+        match $row.get::<bool>($index) {
+            // Depending on the database, an integer column is a `MismatchedTypes` (e.g. postgres) or fails to `Decode`
+            Err(error @ (RowError::Decode { .. } | RowError::MismatchedTypes { .. })) => {
+                let integer = if let Ok(integer) = $row.get::<i64>($index) {
+                    integer
+                } else if let Ok(integer) = $row.get::<i32>($index) {
+                    integer.into()
+                } else if let Ok(integer) = $row.get::<i16>($index) {
+                    integer.into()
+                } else {
+                    return Err(error);
+                };
+                int_to_bool(integer).map_err(|error| RowError::Decode {
+                    index: $index.into(),
+                    source: error.into(),
+                })
+            }
+            result => result,
+        }
+    };
+}
+
+/// Converts an integer stored in a `bool` column
+///
+/// Only `0` and `1` are accepted, any other value is most likely not meant to be a boolean.
+fn int_to_bool(integer: i64) -> Result<bool, InvalidBool> {
+    match integer {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(InvalidBool(integer)),
+    }
+}
+
+/// Error produced by [`BoolDecoder`] for integers other than `0` and `1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidBool(pub i64);
+impl fmt::Display for InvalidBool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected 0 or 1 for a bool, got {}", self.0)
+    }
+}
+impl Error for InvalidBool {}
+
+/// [`FieldDecoder`] for [`bool`]
+///
+/// Schemas created by other tools might store booleans as integers.
+/// Therefore, the decoder falls back to decoding an integer, if decoding a `bool` fails.
+/// Integers other than `0` and `1` are rejected with an [`InvalidBool`] error.
+pub struct BoolDecoder {
+    column: String,
+    index: usize,
+}
+impl Decoder for BoolDecoder {
+    type Result = bool;
+
+    fn by_name<'index>(&'index self, row: &'_ Row) -> Result<Self::Result, RowError<'index>> {
+        decode_bool!(row, self.column.as_str())
+    }
+
+    fn by_index<'index>(&'index self, row: &'_ Row) -> Result<Self::Result, RowError<'index>> {
+        decode_bool!(row, self.index)
+    }
+}
+impl FieldDecoder for BoolDecoder {
+    fn new<I>(ctx: &mut QueryContext, _: FieldProxy<I>) -> Self
+    where
+        I: FieldProxyImpl<Field: Field<Type = Self::Result>>,
+    {
+        let (index, column) = ctx.select_field::<I::Field, I::Path>();
+        Self { column, index }
+    }
+}
+
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, bool> for bool { Value::Bool });
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, Option<bool>> for Option<bool> { |option: Self| option.map(Value::Bool).unwrap_or(Value::Null(NullType::Bool)) });
 
@@ -60,7 +171,14 @@ impl_FieldOrd!(Option<f64>, Option<f64>, |option: Self| option
 impl_FieldSum_FieldAvg!(f64, sum_result: f64);
 impl_FieldMin_FieldMax!(f64);
 
-impl_FieldType!(String, String, conv_string, conv_string);
+impl_FieldType!(
+    String,
+    String,
+    conv_string,
+    conv_string,
+    shared_linter_check<1>,
+    string_annotations
+);
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, &'rhs str> for String { conv_string });
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, &'rhs String> for String { conv_string });
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, String> for String { conv_string });
@@ -78,7 +196,7 @@ fn conv_string<'a>(value: impl Into<Cow<'a, str>>) -> Value<'a> {
     Value::String(value.into())
 }
 
-impl_FieldType!(Vec<u8>, Binary, conv_bytes, conv_bytes);
+impl_FieldType!(Vec<u8>, Binary, conv_bytes, conv_bytes, binary_check);
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, &'rhs [u8]> for Vec<u8> { conv_bytes });
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, &'rhs Vec<u8>> for Vec<u8> { conv_bytes });
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, Vec<u8>> for Vec<u8> { conv_bytes });
@@ -94,3 +212,25 @@ impl_FieldOrd!(Vec<u8>, Cow<'rhs, [u8]>, conv_bytes);
 fn conv_bytes<'a>(value: impl Into<Cow<'a, [u8]>>) -> Value<'a> {
     Value::Binary(value.into())
 }
+
+#[cfg(test)]
+mod test {
+    use super::{int_to_bool, InvalidBool};
+
+    #[test]
+    fn integers_decode_as_bool() {
+        assert_eq!(int_to_bool(0), Ok(false));
+        assert_eq!(int_to_bool(1), Ok(true));
+    }
+
+    #[test]
+    fn other_integers_are_rejected() {
+        assert_eq!(int_to_bool(2), Err(InvalidBool(2)));
+        assert_eq!(int_to_bool(-1), Err(InvalidBool(-1)));
+        assert_eq!(int_to_bool(i64::MAX), Err(InvalidBool(i64::MAX)));
+        assert_eq!(
+            InvalidBool(2).to_string(),
+            "expected 0 or 1 for a bool, got 2"
+        );
+    }
+}
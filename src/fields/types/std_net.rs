@@ -0,0 +1,175 @@
+//! Implementations for [`std::net`]'s ip addresses
+//!
+//! Postgres has a native `inet` type which is used when the "postgres-only" feature is enabled.
+//! Every other database stores the address' canonical string representation.
+//!
+//! Comparing a column to an address uses the same representation:
+//! ```
+//! # use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+//! # use rorm::internal::query_context::QueryContext;
+//! # use rorm::prelude::*;
+//! #[derive(Model)]
+//! struct Host {
+//!     #[rorm(id)]
+//!     id: i64,
+//!     ip: IpAddr,
+//!     v4: Ipv4Addr,
+//!     v6: Option<Ipv6Addr>,
+//! }
+//!
+//! let mut ctx = QueryContext::new();
+//! let ip: IpAddr = "2001:0db8::0001".parse().unwrap();
+//! let index = ctx.add_condition(&Host.ip.equals(ip));
+//! assert_eq!(ctx.display_condition(index).to_string(), "host.ip = '2001:db8::1'");
+//!
+//! let index = ctx.add_condition(&Host.v4.equals(Ipv4Addr::LOCALHOST));
+//! assert_eq!(ctx.display_condition(index).to_string(), "host.v4 = '127.0.0.1'");
+//!
+//! let index = ctx.add_condition(&Host.v6.equals(None));
+//! assert_eq!(ctx.display_condition(index).to_string(), "host.v6 = NULL");
+//! ```
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::conditions::Value;
+use crate::db::sql::value::NullType;
+use crate::fields::traits::{Array, FieldColumns, FieldType};
+#[cfg(not(feature = "postgres-only"))]
+use crate::fields::types::max_str::ImplicitMaxLength;
+use crate::fields::utils::check::shared_linter_check;
+#[cfg(feature = "postgres-only")]
+use crate::fields::utils::get_annotations::forward_annotations;
+#[cfg(not(feature = "postgres-only"))]
+use crate::fields::utils::get_annotations::merge_annotations;
+use crate::fields::utils::get_names::single_column_name;
+use crate::{impl_FieldEq, new_converting_decoder};
+
+/// Implements [`FieldType`] and [`FieldEq`](crate::fields::traits::FieldEq) for an ip address type
+///
+/// `$max_len` is the length of the longest string the type's `Display` impl could produce.
+macro_rules! impl_ip_addr {
+    ($type:ident, $decoder:ident, $max_len:literal, |$network:ident| $from_network:expr) => {
+        impl FieldType for $type {
+            type Columns = Array<1>;
+
+            #[cfg(feature = "postgres-only")]
+            const NULL: FieldColumns<Self, NullType> = [NullType::IpNetwork];
+            #[cfg(not(feature = "postgres-only"))]
+            const NULL: FieldColumns<Self, NullType> = [NullType::String];
+
+            fn into_values<'a>(self) -> FieldColumns<Self, Value<'a>> {
+                [conv_ip(self.into())]
+            }
+
+            fn as_values(&self) -> FieldColumns<Self, Value<'_>> {
+                [conv_ip((*self).into())]
+            }
+
+            type Decoder = $decoder;
+
+            #[cfg(feature = "postgres-only")]
+            type GetAnnotations = forward_annotations<1>;
+            #[cfg(not(feature = "postgres-only"))]
+            type GetAnnotations = merge_annotations<ImplicitMaxLength<$max_len>>;
+
+            type Check = shared_linter_check<1>;
+
+            type GetNames = single_column_name;
+        }
+
+        impl_FieldEq!(impl<'rhs> FieldEq<'rhs, $type> for $type { |ip: $type| conv_ip(ip.into()) });
+        impl_FieldEq!(impl<'rhs> FieldEq<'rhs, Option<$type>> for Option<$type> { |option: Option<$type>| option.map(|ip| conv_ip(ip.into())).unwrap_or(Value::Null(<$type as FieldType>::NULL[0])) });
+
+        #[cfg(feature = "postgres-only")]
+        new_converting_decoder!(
+            pub $decoder,
+            |$network: ipnetwork::IpNetwork| -> $type {
+                $from_network
+            }
+        );
+        #[cfg(not(feature = "postgres-only"))]
+        new_converting_decoder!(
+            pub $decoder,
+            |value: String| -> $type {
+                value
+                    .parse()
+                    .map_err(|err| format!("Couldn't parse ip address: {err}"))
+            }
+        );
+    };
+}
+
+impl_ip_addr!(IpAddr, IpAddrDecoder, 39, |network| {
+    Ok::<_, String>(network.ip())
+});
+impl_ip_addr!(Ipv4Addr, Ipv4AddrDecoder, 15, |network| {
+    match network.ip() {
+        IpAddr::V4(ip) => Ok(ip),
+        IpAddr::V6(ip) => Err(format!("Expected an ipv4 address, got {ip}")),
+    }
+});
+impl_ip_addr!(Ipv6Addr, Ipv6AddrDecoder, 39, |network| {
+    match network.ip() {
+        IpAddr::V4(ip) => Err(format!("Expected an ipv6 address, got {ip}")),
+        IpAddr::V6(ip) => Ok(ip),
+    }
+});
+
+#[cfg(feature = "postgres-only")]
+fn conv_ip<'a>(ip: IpAddr) -> Value<'a> {
+    Value::IpNetwork(ip.into())
+}
+#[cfg(not(feature = "postgres-only"))]
+fn conv_ip<'a>(ip: IpAddr) -> Value<'a> {
+    Value::String(ip.to_string().into())
+}
+
+#[cfg(all(test, not(feature = "postgres-only")))]
+mod test {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    use crate::conditions::Value;
+    use crate::fields::traits::{Array, FieldType};
+
+    fn as_string<T: FieldType<Columns = Array<1>>>(value: T) -> String {
+        match value.into_values() {
+            [Value::String(string)] => string.into_owned(),
+            _ => panic!("ip addresses should be stored as string"),
+        }
+    }
+
+    #[test]
+    fn longest_addresses_fit_max_length() {
+        assert_eq!(as_string(Ipv4Addr::BROADCAST).len(), 15);
+        assert_eq!(as_string(Ipv6Addr::from(u128::MAX)).len(), 39);
+        assert_eq!(as_string(IpAddr::from(Ipv6Addr::from(u128::MAX))).len(), 39);
+    }
+
+    #[test]
+    fn canonical_representation_round_trips() {
+        let addresses: [IpAddr; 6] = [
+            Ipv4Addr::UNSPECIFIED.into(),
+            Ipv4Addr::BROADCAST.into(),
+            Ipv6Addr::LOCALHOST.into(),
+            "2001:0db8:0000:0000:0000:0000:0000:0001".parse().unwrap(),
+            "::ffff:192.0.2.1".parse().unwrap(),
+            Ipv6Addr::from(u128::MAX).into(),
+        ];
+        let expected = [
+            "0.0.0.0",
+            "255.255.255.255",
+            "::1",
+            "2001:db8::1",
+            "::ffff:192.0.2.1",
+            "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+        ];
+        for (address, expected) in addresses.into_iter().zip(expected) {
+            let string = as_string(address);
+            assert_eq!(string, expected);
+            assert_eq!(string.parse::<IpAddr>().unwrap(), address);
+        }
+
+        assert_eq!(as_string(Ipv4Addr::new(10, 0, 0, 1)), "10.0.0.1");
+        assert_eq!(as_string(Ipv6Addr::LOCALHOST), "::1");
+    }
+}
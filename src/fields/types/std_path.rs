@@ -0,0 +1,98 @@
+//! Implementation for [`std::path`]'s [`PathBuf`]
+//!
+//! A path is stored as its UTF-8 string without any normalization,
+//! i.e. Windows' `\` separators are preserved verbatim and read back as they were written:
+//! ```
+//! use std::path::PathBuf;
+//! use rorm::conditions::Value;
+//! use rorm::fields::traits::FieldType;
+//!
+//! let path = PathBuf::from(r"C:\Users\alice\notes.txt");
+//! let [Value::String(string)] = path.as_values() else {
+//!     unreachable!();
+//! };
+//! assert_eq!(string, r"C:\Users\alice\notes.txt");
+//! assert_eq!(PathBuf::from(string.as_ref()), path);
+//! ```
+//!
+//! Paths which are not valid UTF-8 can't be stored in a string column.
+//! They are converted to [`Value::Invalid`] which makes the query fail
+//! when inserting, updating or comparing them, before anything is sent to the database:
+//! ```
+//! # #[cfg(unix)] {
+//! use std::ffi::OsStr;
+//! use std::os::unix::ffi::OsStrExt;
+//! use std::path::PathBuf;
+//! use rorm::conditions::Value;
+//! use rorm::fields::traits::FieldType;
+//!
+//! let path = PathBuf::from(OsStr::from_bytes(b"/tmp/\xff"));
+//! let [value] = path.as_values();
+//! assert!(value.try_as_sql().is_err());
+//! # }
+//! ```
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use rorm_db::sql::value::NullType;
+
+use crate::conditions::Value;
+use crate::fields::traits::{Array, FieldColumns, FieldType};
+use crate::fields::utils::check::string_check;
+use crate::fields::utils::get_annotations::string_annotations;
+use crate::fields::utils::get_names::single_column_name;
+use crate::{impl_FieldEq, new_converting_decoder};
+
+impl_FieldEq!(impl<'rhs> FieldEq<'rhs, &'rhs Path> for PathBuf { conv_path });
+impl_FieldEq!(impl<'rhs> FieldEq<'rhs, &'rhs PathBuf> for PathBuf { |path: &'rhs PathBuf| conv_path(path) });
+impl_FieldEq!(impl<'rhs> FieldEq<'rhs, PathBuf> for PathBuf { conv_path_buf });
+
+impl FieldType for PathBuf {
+    type Columns = Array<1>;
+
+    const NULL: FieldColumns<Self, NullType> = [NullType::String];
+
+    fn into_values<'a>(self) -> FieldColumns<Self, Value<'a>> {
+        [conv_path_buf(self)]
+    }
+
+    #[inline(always)]
+    fn as_values(&self) -> FieldColumns<Self, Value<'_>> {
+        [conv_path(self)]
+    }
+
+    type Decoder = PathBufDecoder;
+
+    type GetAnnotations = string_annotations;
+
+    type Check = string_check;
+
+    type GetNames = single_column_name;
+}
+new_converting_decoder!(
+    pub PathBufDecoder,
+    |value: String| -> PathBuf {
+        Ok::<_, String>(PathBuf::from(value))
+    }
+);
+
+fn conv_path(path: &Path) -> Value<'_> {
+    match path.to_str() {
+        Some(string) => Value::String(Cow::Borrowed(string)),
+        None => invalid_path(path),
+    }
+}
+
+fn conv_path_buf<'a>(path: PathBuf) -> Value<'a> {
+    match path.into_os_string().into_string() {
+        Ok(string) => Value::String(Cow::Owned(string)),
+        Err(os_string) => invalid_path(Path::new(&os_string)),
+    }
+}
+
+fn invalid_path<'a>(path: &Path) -> Value<'a> {
+    Value::Invalid(Cow::Owned(format!(
+        "the path {path:?} is not valid UTF-8 and can't be stored as string"
+    )))
+}
@@ -2,7 +2,9 @@ use rorm_db::sql::value::NullType;
 use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
 
 use crate::conditions::Value;
-use crate::{impl_FieldEq, impl_FieldMin_FieldMax, impl_FieldOrd, impl_FieldType};
+use crate::{
+    impl_FieldEq, impl_FieldEq_FieldOrd_day, impl_FieldMin_FieldMax, impl_FieldOrd, impl_FieldType,
+};
 
 impl_FieldType!(Time, TimeTime, Value::TimeTime);
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, Time> for Time { Value::TimeTime });
@@ -59,3 +61,15 @@ impl_FieldOrd!(
         .unwrap_or(Value::Null(NullType::TimePrimitiveDateTime))
 );
 impl_FieldMin_FieldMax!(PrimitiveDateTime);
+// A date is compared as its whole day
+// (`OffsetDateTime` doesn't get this, because a date doesn't know its offset)
+impl_FieldEq_FieldOrd_day!(PrimitiveDateTime, Date, |date: Date| {
+    let end = date
+        .next_day()
+        .map(Date::midnight)
+        .unwrap_or(PrimitiveDateTime::MAX);
+    (
+        Value::TimePrimitiveDateTime(date.midnight()),
+        Value::TimePrimitiveDateTime(end),
+    )
+});
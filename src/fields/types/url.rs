@@ -6,7 +6,7 @@ use url::Url;
 use crate::conditions::Value;
 use crate::fields::traits::{Array, FieldColumns, FieldType};
 use crate::fields::utils::check::string_check;
-use crate::fields::utils::get_annotations::forward_annotations;
+use crate::fields::utils::get_annotations::string_annotations;
 use crate::fields::utils::get_names::single_column_name;
 use crate::{impl_FieldEq, new_converting_decoder};
 
@@ -29,7 +29,7 @@ impl FieldType for Url {
 
     type Decoder = UrlDecoder;
 
-    type GetAnnotations = forward_annotations<1>;
+    type GetAnnotations = string_annotations;
 
     type Check = string_check;
 
@@ -2,8 +2,15 @@ use rorm_db::sql::value::NullType;
 use uuid::Uuid;
 
 use crate::conditions::Value;
+use crate::fields::utils::check::uuid_check;
 use crate::{impl_FieldEq, impl_FieldType};
 
-impl_FieldType!(Uuid, Uuid, Value::Uuid);
+impl_FieldType!(
+    Uuid,
+    Uuid,
+    Value::Uuid,
+    |&value| Value::Uuid(value),
+    uuid_check
+);
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, Uuid> for Uuid { Value::Uuid });
 impl_FieldEq!(impl<'rhs> FieldEq<'rhs, Option<Uuid>> for Option<Uuid> { |option: Option<_>| option.map(Value::Uuid).unwrap_or(Value::Null(NullType::Uuid)) });
@@ -4,7 +4,8 @@ use crate::const_fn;
 #[cfg(doc)]
 use crate::fields::traits::FieldType;
 use crate::internal::const_concat::ConstString;
-use crate::internal::hmr::annotations::Annotations;
+use crate::internal::field::DEFAULT_MAX_LENGTH;
+use crate::internal::hmr::annotations::{Annotations, DefaultValue, DefaultValueData};
 
 const_fn! {
     /// [`FieldType::Check`] which checks the explicit annotations to be empty.
@@ -18,11 +19,13 @@ const_fn! {
                 default: None,
                 index: None,
                 max_length: None,
+                no_constraint: None,
                 on_delete: None,
                 on_update: None,
                 primary_key: None,
                 unique: None,
                 nullable: false,
+                explicit_nullable: None,
                 foreign: None,
             } => Ok(()),
             _ => Err(ConstString::error(&["BackRef doesn't take any annotations"])),
@@ -40,6 +43,24 @@ const_fn! {
             if let Err(err) = column.as_lint().check() {
                 return Err(ConstString::error(&["invalid annotations: ", err]));
             }
+
+            if column.no_constraint.is_some() && column.foreign.is_none() {
+                return Err(ConstString::error(&[
+                    "invalid annotations: foreign(no_constraint) can only be used on foreign models",
+                ]));
+            }
+
+            if column.primary_key.is_some() && matches!(column.explicit_nullable, Some(true)) {
+                return Err(ConstString::error(&[
+                    "invalid annotations: a primary key can't be nullable",
+                ]));
+            }
+
+            if let Some(DefaultValue(DefaultValueData::Binary(_))) = column.default {
+                return Err(ConstString::error(&[
+                    "invalid annotations: byte string defaults can only be used on binary fields",
+                ]));
+            }
         }
         Ok(())
     }
@@ -47,13 +68,13 @@ const_fn! {
 
 const_fn! {
     /// [`FieldType::Check`] which runs the linter shared with `rorm-cli` on every column
-    /// and checks `max_length` to be set.
+    /// and checks `max_length` to be set, unless there is a [`DEFAULT_MAX_LENGTH`] to fall back to.
     pub fn string_check(_field: Annotations, [column]: [Annotations; 1]) -> Result<(), ConstString<1024>> {
         if let Err(error) = shared_linter_check(_field, [column]) {
             return Err(error);
         }
 
-        if column.max_length.is_none() {
+        if column.max_length.is_none() && DEFAULT_MAX_LENGTH.is_none() {
             return Err(ConstString::error(&[
                 "missing annotation: max_length",
             ]));
@@ -62,3 +83,83 @@ const_fn! {
         Ok(())
     }
 }
+
+const_fn! {
+    /// [`FieldType::Check`] which runs the linter shared with `rorm-cli` on every column
+    /// and allows a byte string as `default`.
+    ///
+    /// The byte string is passed to the imr as hexadecimal string:
+    /// ```
+    /// use rorm::imr::{Annotation, DefaultValue};
+    /// use rorm::Model;
+    ///
+    /// #[derive(Model)]
+    /// struct Blob {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///
+    ///     #[rorm(default = b"\x00\xc0\xff\xee")]
+    ///     data: Vec<u8>,
+    /// }
+    ///
+    /// let imr = Blob::get_imr();
+    /// let data = imr.fields.iter().find(|field| field.name == "data").unwrap();
+    /// assert!(data
+    ///     .annotations
+    ///     .contains(&Annotation::DefaultValue(DefaultValue::String("00c0ffee".to_string()))));
+    /// ```
+    pub fn binary_check(_field: Annotations, [column]: [Annotations; 1]) -> Result<(), ConstString<1024>> {
+        let mut column = column;
+        if let Some(DefaultValue(DefaultValueData::Binary(hex))) = column.default {
+            // Let the linter check the default as the string the imr will contain
+            column.default = Some(DefaultValue(DefaultValueData::String(hex)));
+        }
+        shared_linter_check(_field, [column])
+    }
+}
+
+const_fn! {
+    /// [`FieldType::Check`] which runs the linter shared with `rorm-cli` on every column
+    /// and checks a `default` to be a valid uuid string.
+    pub fn uuid_check(_field: Annotations, [column]: [Annotations; 1]) -> Result<(), ConstString<1024>> {
+        if let Err(error) = shared_linter_check(_field, [column]) {
+            return Err(error);
+        }
+
+        match column.default {
+            None => Ok(()),
+            Some(DefaultValue(DefaultValueData::String(string))) => {
+                if is_uuid(string.as_bytes()) {
+                    Ok(())
+                } else {
+                    Err(ConstString::error(&["invalid annotations: default is not a valid uuid: ", string]))
+                }
+            }
+            Some(_) => Err(ConstString::error(&["invalid annotations: default of a uuid has to be a string"])),
+        }
+    }
+}
+
+/// Checks a string to be a uuid in its hyphenated (`67e55044-10b1-426f-9247-bb680e5fe0c8`)
+/// or simple (`67e5504410b1426f9247bb680e5fe0c8`) format
+const fn is_uuid(string: &[u8]) -> bool {
+    let hyphenated = string.len() == 36;
+    if !hyphenated && string.len() != 32 {
+        return false;
+    }
+
+    let mut index = 0;
+    while index < string.len() {
+        let is_hyphen_position = hyphenated && matches!(index, 8 | 13 | 18 | 23);
+        let valid = if is_hyphen_position {
+            string[index] == b'-'
+        } else {
+            string[index].is_ascii_hexdigit()
+        };
+        if !valid {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
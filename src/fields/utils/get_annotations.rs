@@ -5,6 +5,7 @@ use crate::const_fn;
 use crate::fields::traits::FieldType;
 use crate::fields::utils::const_fn::Contains;
 use crate::internal::const_concat::ConstString;
+use crate::internal::field::{apply_default_max_length, DEFAULT_MAX_LENGTH};
 use crate::internal::hmr::annotations::Annotations;
 
 const_fn! {
@@ -40,3 +41,11 @@ const_fn! {
         [field]
     }
 }
+
+const_fn! {
+    /// [`FieldType::GetAnnotations`] for string columns which forwards the explicit annotations
+    /// and falls back to [`DEFAULT_MAX_LENGTH`] if `max_length` is missing.
+    pub fn string_annotations(field: Annotations) -> [Annotations; 1] {
+        [apply_default_max_length(field, DEFAULT_MAX_LENGTH)]
+    }
+}
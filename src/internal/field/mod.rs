@@ -44,7 +44,7 @@ use rorm_declaration::imr;
 use crate::conditions::Value;
 use crate::fields::proxy::FieldProxy;
 use crate::fields::proxy::FieldProxyImpl;
-use crate::internal::hmr::annotations::Annotations;
+use crate::internal::hmr::annotations::{Annotations, MaxLength};
 use crate::internal::hmr::{AsImr, Source};
 use crate::internal::relation_path::{Path, PathField};
 use crate::model::{ConstNew, Model};
@@ -98,6 +98,71 @@ pub trait Field: 'static + Copy {
     fn new() -> Self;
 }
 
+/// `max_length` applied to string columns which don't set one explicitly
+///
+/// This is opt-in and `None` by default, i.e. a missing `max_length` is reported by the migrator.
+/// To let prototypes get away without annotating every string,
+/// set the environment variable `RORM_DEFAULT_MAX_LENGTH` while compiling rorm,
+/// for example in your project's `.cargo/config.toml`:
+/// ```toml
+/// [env]
+/// RORM_DEFAULT_MAX_LENGTH = "255"
+/// ```
+///
+/// The default is added to the annotations of `String`, `Url` and `PathBuf` fields at compile time,
+/// so the database still enforces it and derived schemas (`utoipa`, `schemars`) report it as `maxLength`.
+pub const DEFAULT_MAX_LENGTH: Option<i32> =
+    parse_max_length(option_env!("RORM_DEFAULT_MAX_LENGTH"));
+
+/// Parses the value of `RORM_DEFAULT_MAX_LENGTH`
+///
+/// # Panics
+/// If the value is not a positive integer, which turns into a compile error when evaluated as constant.
+const fn parse_max_length(string: Option<&str>) -> Option<i32> {
+    let Some(string) = string else {
+        return None;
+    };
+    let bytes = string.as_bytes();
+    if bytes.is_empty() {
+        panic!("RORM_DEFAULT_MAX_LENGTH is empty");
+    }
+
+    let mut max_length: i32 = 0;
+    let mut index = 0;
+    while index < bytes.len() {
+        let digit = bytes[index];
+        if !digit.is_ascii_digit() {
+            panic!("RORM_DEFAULT_MAX_LENGTH is not a positive integer");
+        }
+        max_length = match max_length.checked_mul(10) {
+            Some(shifted) => match shifted.checked_add((digit - b'0') as i32) {
+                Some(max_length) => max_length,
+                None => panic!("RORM_DEFAULT_MAX_LENGTH is too large"),
+            },
+            None => panic!("RORM_DEFAULT_MAX_LENGTH is too large"),
+        };
+        index += 1;
+    }
+    if max_length == 0 {
+        panic!("RORM_DEFAULT_MAX_LENGTH is not a positive integer");
+    }
+    Some(max_length)
+}
+
+/// Sets a string column's `max_length` to `default` if it doesn't have one
+pub(crate) const fn apply_default_max_length(
+    annotations: Annotations,
+    default: Option<i32>,
+) -> Annotations {
+    let mut annotations = annotations;
+    if annotations.max_length.is_none() {
+        if let Some(default) = default {
+            annotations.max_length = Some(MaxLength(default));
+        }
+    }
+    annotations
+}
+
 /// Pushes a [`Field`]'s columns as [`imr`] onto a vector.
 ///
 /// This function is called by the `#[derive(Model)]` macro to gather a list of all vectors.
@@ -254,3 +319,33 @@ mod contains {
         const ITEM: &'static str = F::NAME;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{apply_default_max_length, parse_max_length};
+    use crate::internal::hmr::annotations::{Annotations, MaxLength};
+
+    #[test]
+    fn parse_default_max_length() {
+        assert_eq!(parse_max_length(None), None);
+        assert_eq!(parse_max_length(Some("255")), Some(255));
+        assert_eq!(parse_max_length(Some("2147483647")), Some(i32::MAX));
+    }
+
+    #[test]
+    fn default_max_length_is_opt_in() {
+        let annotations = apply_default_max_length(Annotations::empty(), None);
+        assert!(annotations.max_length.is_none());
+
+        let annotations = apply_default_max_length(Annotations::empty(), Some(255));
+        assert!(matches!(annotations.max_length, Some(MaxLength(255))));
+    }
+
+    #[test]
+    fn default_max_length_keeps_explicit() {
+        let mut annotations = Annotations::empty();
+        annotations.max_length = Some(MaxLength(16));
+        let annotations = apply_default_max_length(annotations, Some(255));
+        assert!(matches!(annotations.max_length, Some(MaxLength(16))));
+    }
+}
@@ -59,6 +59,13 @@ pub struct ForeignKey {
     pub column_name: &'static str,
 }
 
+/// Omit the foreign key constraint from the database
+///
+/// The field can still be used to join its related model,
+/// but the database won't ensure the referenced row exists.
+#[derive(Copy, Clone)]
+pub struct NoConstraint;
+
 /// Action to take on a foreign key in case of on delete
 pub type OnDelete = imr::ReferentialAction;
 
@@ -80,8 +87,12 @@ pub struct IndexData {
 /// A column's default value which is any non object / array json value
 #[derive(Copy, Clone)]
 pub enum DefaultValueData {
-    /// Use hexadecimal to represent binary data
+    /// Just a string.
     String(&'static str),
+    /// Binary data represented using hexadecimal
+    ///
+    /// Only binary columns accept this kind of default.
+    Binary(&'static str),
     /// i64 is used as it can represent any integer defined in DbType
     Integer(i64),
     /// Ordered float is used as f64 does not Eq and Order which are needed for Hash
@@ -109,6 +120,8 @@ impl AsImr for DefaultValueData {
     fn as_imr(&self) -> Self::Imr {
         match self {
             DefaultValueData::String(string) => imr::DefaultValue::String(string.to_string()),
+            // The imr uses hexadecimal strings for binary data
+            DefaultValueData::Binary(hex) => imr::DefaultValue::String(hex.to_string()),
             DefaultValueData::Integer(integer) => imr::DefaultValue::Integer(*integer),
             DefaultValueData::Float(float) => imr::DefaultValue::Float((*float).into()),
             DefaultValueData::Boolean(boolean) => imr::DefaultValue::Boolean(*boolean),
@@ -156,6 +169,9 @@ pub struct Annotations {
     /// The `#[rorm(max_length = ..)]` annotation
     pub max_length: Option<MaxLength>,
 
+    /// The `#[rorm(foreign(no_constraint))]` annotation
+    pub no_constraint: Option<NoConstraint>,
+
     /// The `#[rorm(on_delete = ..)]` annotation
     pub on_delete: Option<OnDelete>,
 
@@ -171,6 +187,11 @@ pub struct Annotations {
     /// Set implicitly if type is `Option<T>`
     pub nullable: bool,
 
+    /// The `#[rorm(nullable)]` (`Some(true)`) or `#[rorm(not_null)]` (`Some(false)`) annotation
+    ///
+    /// It takes precedence over the implicit `nullable`.
+    pub explicit_nullable: Option<bool>,
+
     /// Set implicitly if type is `ForeignModel<M>`
     pub foreign: Option<ForeignKey>,
 }
@@ -190,12 +211,14 @@ impl AsImr for Annotations {
             default,
             index,
             max_length,
+            no_constraint,
             foreign,
             on_delete,
             on_update,
             primary_key,
             unique,
-            nullable: _, // Set via not_null()
+            nullable: _,          // Set via not_null()
+            explicit_nullable: _, //
         } = self;
         let mut annotations = Vec::new();
         if let Some(_) = auto_create_time {
@@ -219,7 +242,7 @@ impl AsImr for Annotations {
         if let Some(max_length) = max_length {
             annotations.push(max_length.as_imr());
         }
-        if let Some(foreign) = foreign {
+        if let (Some(foreign), None) = (foreign, no_constraint) {
             annotations.push(imr::Annotation::ForeignKey(imr::ForeignKey {
                 table_name: foreign.table_name.to_string(),
                 column_name: foreign.column_name.to_string(),
@@ -251,17 +274,22 @@ impl Annotations {
             default: None,
             index: None,
             max_length: None,
+            no_constraint: None,
             on_delete: None,
             on_update: None,
             primary_key: None,
             unique: None,
             nullable: false,
+            explicit_nullable: None,
             foreign: None,
         }
     }
 
     /// Is SQL's not null annotation set?
     pub const fn not_null(&self) -> bool {
+        if let Some(nullable) = self.explicit_nullable {
+            return !nullable;
+        }
         let implicit = self.primary_key.is_some();
         !self.nullable && !implicit
     }
@@ -325,11 +353,13 @@ impl Annotations {
             default,
             index,
             max_length,
+            no_constraint,
             foreign,
             on_delete,
             on_update,
             primary_key,
             unique,
+            explicit_nullable,
         } = other;);
         Ok(self)
     }
@@ -0,0 +1,42 @@
+//! Helpers used by the code generated for `#[rorm(schemars)]`
+
+pub use schemars::gen::SchemaGenerator;
+use schemars::schema::InstanceType;
+pub use schemars::schema::{Schema, SchemaObject};
+pub use schemars::JsonSchema;
+
+use crate::fields::traits::FieldType;
+use crate::internal::field::SingleColumnField;
+
+/// Construct the object for a model's or patch's schema
+pub fn new_object() -> SchemaObject {
+    SchemaObject {
+        instance_type: Some(InstanceType::Object.into()),
+        ..Default::default()
+    }
+}
+
+/// Add a field as property to a model's or patch's schema
+///
+/// The field's `max_length` annotation is added to the property's schema
+/// and the property is marked as required unless the field is an `Option`.
+pub fn add_field<F>(gen: &mut SchemaGenerator, object: &mut SchemaObject, name: &str)
+where
+    F: SingleColumnField,
+    F::Type: JsonSchema,
+{
+    let mut schema = gen.subschema_for::<F::Type>();
+    if let (Schema::Object(schema), Some(max_length)) =
+        (&mut schema, F::EFFECTIVE_ANNOTATION.max_length)
+    {
+        if !schema.is_ref() {
+            schema.string().max_length = Some(max_length.0 as u32);
+        }
+    }
+
+    let object = object.object();
+    if !<F::Type as FieldType>::is_option::<()>() {
+        object.required.insert(name.to_string());
+    }
+    object.properties.insert(name.to_string(), schema);
+}
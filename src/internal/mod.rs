@@ -7,12 +7,26 @@ pub mod const_concat;
 mod djb2;
 pub mod field;
 pub mod hmr;
+#[cfg(feature = "schemars")]
+pub mod json_schema;
 pub mod patch;
 pub mod query_context;
 pub mod relation_path;
+#[cfg(feature = "utoipa")]
+pub mod to_schema;
 
 pub use rorm_declaration::imr;
 
+use crate::crud::update::Partial;
+use crate::Model;
+
+/// **Use [`partial!`](crate::partial), this function is its impl detail!**
+///
+/// Creates an empty [`Partial`] which the macro adds its columns to.
+pub fn new_partial<M: Model>() -> Partial<M> {
+    Partial::new()
+}
+
 /// Wrap a `Fn(&mut std::fmt::Formatter<'_>) -> std::fmt::Result` to implement [`Display`](std::fmt::Display)
 pub struct DisplayImpl<F: Fn(&mut std::fmt::Formatter<'_>) -> std::fmt::Result>(
     /// The wrapped closure
@@ -8,8 +8,10 @@
 //! and the `Condition` [`Column`] requires generating join aliases (owned strings)
 //! after the use constructed his condition tree.
 
+use std::fmt;
+
 use crate::conditions::collections::CollectionOperator;
-use crate::conditions::{BinaryOperator, TernaryOperator, UnaryOperator};
+use crate::conditions::{BinaryOperator, TernaryOperator, UnaryOperator, Value};
 use crate::internal::query_context::QueryContext;
 use crate::internal::relation_path::PathId;
 
@@ -31,7 +33,7 @@ pub(crate) enum FlatCondition {
 
 /// Error returned by [`QueryContext::try_get_condition`]
 ///
-/// The error's cause is either a bad `Condition` implementation
+/// Apart from [`GetConditionError::InvalidValue`], the error's cause is either a bad `Condition` implementation
 /// or an invalid index passed to the method.
 ///
 /// I.e. it's always some programmer's fault.
@@ -48,6 +50,11 @@ pub enum GetConditionError {
 
     /// Invalid table key
     UnknownAlias,
+
+    /// A [`Value::Invalid`] is used by the condition
+    ///
+    /// [`QueryContext::check_values`] returns its message as error.
+    InvalidValue,
 }
 
 impl QueryContext<'_> {
@@ -118,7 +125,8 @@ impl QueryContext<'_> {
                 ])))
             }
             FlatCondition::Value(index) => {
-                sql::Condition::Value(self.values.get(index).ok_or(UnknownValue)?.as_sql())
+                let value = self.values.get(index).ok_or(UnknownValue)?;
+                sql::Condition::Value(value.try_as_sql().map_err(|_| InvalidValue)?)
             }
             FlatCondition::Column(table_name, column_name) => {
                 sql::Condition::Value(sql::Value::Column {
@@ -129,3 +137,176 @@ impl QueryContext<'_> {
         })
     }
 }
+
+/// Human-readable rendering of a condition stored in a [`QueryContext`]
+///
+/// Returned by [`QueryContext::display_condition`].
+///
+/// The output resembles sql but doesn't follow any specific dialect.
+/// It is meant for logging and debugging and must not be sent to a database.
+pub struct DisplayCondition<'ctx, 'v> {
+    pub(super) context: &'ctx QueryContext<'v>,
+    pub(super) index: usize,
+}
+
+impl fmt::Display for DisplayCondition<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Validate the condition first, so the rendering below doesn't have to report errors
+        if let Err(error) = self.context.try_get_condition(self.index) {
+            return write!(f, "<invalid condition: {error:?}>");
+        }
+
+        let mut nodes = self.context.conditions[self.index..].iter().copied();
+        let head = nodes.next().ok_or(fmt::Error)?;
+        self.fmt_inner(f, head, &mut nodes, false)
+    }
+}
+
+impl DisplayCondition<'_, '_> {
+    /// Writes a single node and its arguments
+    ///
+    /// `parens` wraps collections in parentheses when they are used as argument to an operator.
+    fn fmt_inner(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        head: FlatCondition,
+        tail: &mut impl Iterator<Item = FlatCondition>,
+        parens: bool,
+    ) -> fmt::Result {
+        let arg = |f: &mut fmt::Formatter<'_>, tail: &mut _| {
+            let head = Iterator::next(tail).ok_or(fmt::Error)?;
+            self.fmt_inner(f, head, tail, true)
+        };
+
+        match head {
+            FlatCondition::StartCollection(op) => {
+                let (separator, empty) = match op {
+                    CollectionOperator::And => (" AND ", "TRUE"),
+                    CollectionOperator::Or => (" OR ", "FALSE"),
+                };
+                let mut args = 0;
+                loop {
+                    let head = tail.next().ok_or(fmt::Error)?;
+                    if matches!(head, FlatCondition::EndCollection) {
+                        break;
+                    }
+                    if args == 0 && parens {
+                        f.write_str("(")?;
+                    }
+                    if args > 0 {
+                        f.write_str(separator)?;
+                    }
+                    f.write_str("(")?;
+                    self.fmt_inner(f, head, tail, false)?;
+                    f.write_str(")")?;
+                    args += 1;
+                }
+                if args == 0 {
+                    f.write_str(empty)?;
+                } else if parens {
+                    f.write_str(")")?;
+                }
+            }
+            FlatCondition::EndCollection => return Err(fmt::Error),
+            FlatCondition::UnaryCondition(op) => {
+                let (prefix, postfix) = match op {
+                    UnaryOperator::IsNull => ("", " IS NULL"),
+                    UnaryOperator::IsNotNull => ("", " IS NOT NULL"),
+                    UnaryOperator::Exists => ("EXISTS ", ""),
+                    UnaryOperator::NotExists => ("NOT EXISTS ", ""),
+                    UnaryOperator::Not => ("NOT ", ""),
+                };
+                f.write_str(prefix)?;
+                arg(f, tail)?;
+                f.write_str(postfix)?;
+            }
+            FlatCondition::BinaryCondition(op) => {
+                let op = match op {
+                    BinaryOperator::Equals => " = ",
+                    BinaryOperator::NotEquals => " <> ",
+                    BinaryOperator::Greater => " > ",
+                    BinaryOperator::GreaterOrEquals => " >= ",
+                    BinaryOperator::Less => " < ",
+                    BinaryOperator::LessOrEquals => " <= ",
+                    BinaryOperator::Like => " LIKE ",
+                    BinaryOperator::NotLike => " NOT LIKE ",
+                    BinaryOperator::Regexp => " REGEXP ",
+                    BinaryOperator::NotRegexp => " NOT REGEXP ",
+                };
+                arg(f, tail)?;
+                f.write_str(op)?;
+                arg(f, tail)?;
+            }
+            FlatCondition::TernaryCondition(op) => {
+                let op = match op {
+                    TernaryOperator::Between => " BETWEEN ",
+                    TernaryOperator::NotBetween => " NOT BETWEEN ",
+                };
+                arg(f, tail)?;
+                f.write_str(op)?;
+                arg(f, tail)?;
+                f.write_str(" AND ")?;
+                arg(f, tail)?;
+            }
+            FlatCondition::Value(index) => {
+                fmt_value(f, self.context.values.get(index).ok_or(fmt::Error)?)?;
+            }
+            FlatCondition::Column(table_name, column_name) => {
+                let table_name = self
+                    .context
+                    .join_aliases
+                    .get(&table_name)
+                    .ok_or(fmt::Error)?;
+                write!(f, "{table_name}.{column_name}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes a value as literal
+fn fmt_value(f: &mut fmt::Formatter<'_>, value: &Value) -> fmt::Result {
+    match value {
+        Value::Null(_) => f.write_str("NULL"),
+        Value::String(v) | Value::Choice(v) => write!(f, "'{}'", v.replace('\'', "''")),
+        Value::I64(v) => write!(f, "{v}"),
+        Value::I32(v) => write!(f, "{v}"),
+        Value::I16(v) => write!(f, "{v}"),
+        Value::Bool(true) => f.write_str("TRUE"),
+        Value::Bool(false) => f.write_str("FALSE"),
+        Value::F64(v) => write!(f, "{v}"),
+        Value::F32(v) => write!(f, "{v}"),
+        Value::Binary(v) => {
+            f.write_str("x'")?;
+            for byte in v.iter() {
+                write!(f, "{byte:02x}")?;
+            }
+            f.write_str("'")
+        }
+        #[cfg(feature = "chrono")]
+        Value::ChronoNaiveTime(v) => write!(f, "'{v}'"),
+        #[cfg(feature = "chrono")]
+        Value::ChronoNaiveDate(v) => write!(f, "'{v}'"),
+        #[cfg(feature = "chrono")]
+        Value::ChronoNaiveDateTime(v) => write!(f, "'{v}'"),
+        #[cfg(feature = "chrono")]
+        Value::ChronoDateTime(v) => write!(f, "'{v}'"),
+        #[cfg(feature = "time")]
+        Value::TimeDate(v) => write!(f, "'{v}'"),
+        #[cfg(feature = "time")]
+        Value::TimeTime(v) => write!(f, "'{v}'"),
+        #[cfg(feature = "time")]
+        Value::TimeOffsetDateTime(v) => write!(f, "'{v}'"),
+        #[cfg(feature = "time")]
+        Value::TimePrimitiveDateTime(v) => write!(f, "'{v}'"),
+        #[cfg(feature = "uuid")]
+        Value::Uuid(v) => write!(f, "'{v}'"),
+        #[cfg(feature = "postgres-only")]
+        Value::MacAddress(v) => write!(f, "'{v}'"),
+        #[cfg(feature = "postgres-only")]
+        Value::IpNetwork(v) => write!(f, "'{v}'"),
+        #[cfg(feature = "postgres-only")]
+        Value::BitVec(v) => write!(f, "B'{:?}'", v.as_ref()),
+        Value::Invalid(message) => write!(f, "<invalid value: {message}>"),
+    }
+}
@@ -2,24 +2,36 @@
 
 use std::borrow::Cow;
 use std::collections::HashMap;
-use std::fmt::Write;
 use std::ops::{Deref, DerefMut};
 use std::{fmt, mem};
 
 use rorm_db::sql::join_table::JoinType;
 use rorm_db::sql::ordering::Ordering;
+use rorm_db::Error;
 use tracing::{trace, trace_span, Span};
 
 use crate::conditions::{BinaryOperator, Condition, Value};
 use crate::crud::selector::AggregatedColumn;
 use crate::fields::proxy::FieldProxyImpl;
 use crate::internal::field::Field;
-use crate::internal::query_context::flat_conditions::{FlatCondition, GetConditionError};
+use crate::internal::query_context::flat_conditions::{
+    DisplayCondition, FlatCondition, GetConditionError,
+};
 use crate::internal::relation_path::{Path, PathField, PathId};
 use crate::Model;
 
 pub mod flat_conditions;
 
+/// Prefix used by default for the aliases of joined tables
+///
+/// See [`QueryContext::set_alias_prefixes`]
+pub const DEFAULT_JOIN_ALIAS_PREFIX: &str = "__t";
+
+/// Prefix used by default for the aliases of selected columns
+///
+/// See [`QueryContext::set_alias_prefixes`]
+pub const DEFAULT_SELECT_ALIAS_PREFIX: &str = "__c";
+
 /// Context for creating queries.
 ///
 /// Since rorm-db borrows all of its parameters, there has to be someone who own it.
@@ -28,6 +40,10 @@ pub mod flat_conditions;
 pub struct QueryContext<'v> {
     span: Span,
     base_path: Option<PathId>,
+    left_joins: bool,
+    column_names_as_aliases: bool,
+    join_alias_prefix: &'static str,
+    select_alias_prefix: &'static str,
 
     join_aliases: HashMap<PathId, String>,
     selects: Vec<Select>,
@@ -41,6 +57,10 @@ impl Default for QueryContext<'_> {
         Self {
             span: Span::none(),
             base_path: Default::default(),
+            left_joins: false,
+            column_names_as_aliases: false,
+            join_alias_prefix: DEFAULT_JOIN_ALIAS_PREFIX,
+            select_alias_prefix: DEFAULT_SELECT_ALIAS_PREFIX,
             join_aliases: Default::default(),
             selects: Default::default(),
             joins: Default::default(),
@@ -56,10 +76,82 @@ impl<'v> QueryContext<'v> {
         Self::default()
     }
 
+    /// Change the prefixes prepended to generated join and select aliases
+    ///
+    /// The defaults ([`DEFAULT_JOIN_ALIAS_PREFIX`] and [`DEFAULT_SELECT_ALIAS_PREFIX`])
+    /// contain a double underscore which is forbidden in table and column names.
+    /// This guarantees that the generated aliases never shadow a real identifier.
+    ///
+    /// The prefixes only apply to aliases generated after calling this method,
+    /// so it should be called on a fresh context.
+    ///
+    /// ```
+    /// # use rorm::internal::query_context::QueryContext;
+    /// # use rorm::prelude::*;
+    /// # #[derive(Model)]
+    /// # struct User {
+    /// #     #[rorm(id)]
+    /// #     id: i64,
+    /// # }
+    /// use rorm::crud::selector::Selector;
+    ///
+    /// let mut ctx = QueryContext::new();
+    /// ctx.set_alias_prefixes("join_", "column_");
+    /// User.id.select(&mut ctx);
+    /// assert_eq!(ctx.get_selects()[0].select_alias, Some("column_a"));
+    /// ```
+    pub fn set_alias_prefixes(&mut self, join: &'static str, select: &'static str) {
+        self.join_alias_prefix = join;
+        self.select_alias_prefix = select;
+    }
+
+    /// Get the alias used for a path's table, joining the path if it isn't already
+    ///
+    /// This can be used to reference a specific join (for example one side of a self-join)
+    /// in a hand-written sql fragment.
+    ///
+    /// ```
+    /// # use rorm::internal::query_context::QueryContext;
+    /// # use rorm::fields::types::ForeignModel;
+    /// # use rorm::prelude::*;
+    /// #[derive(Model)]
+    /// struct Comment {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///     parent: Option<ForeignModel<Comment>>,
+    /// }
+    ///
+    /// let mut ctx = QueryContext::new();
+    /// assert_eq!(ctx.alias_for::<Comment>(), "comment");
+    /// assert_eq!(ctx.alias_for::<(__Comment_parent, Comment)>(), "__tb");
+    /// assert_eq!(ctx.alias_for::<(__Comment_parent, (__Comment_parent, Comment))>(), "__tc");
+    ///
+    /// // Resolving an alias again doesn't join the path a second time
+    /// assert_eq!(ctx.alias_for::<(__Comment_parent, Comment)>(), "__tb");
+    /// assert_eq!(ctx.get_joins().len(), 2);
+    /// ```
+    pub fn alias_for<P: Path>(&mut self) -> &str {
+        let path_id = P::add_to_context(self);
+        &self.join_aliases[&path_id]
+    }
+
+    /// Generate the alias for the next select
+    fn next_select_alias(&self, column_name: impl FnOnce() -> String) -> String {
+        if self.column_names_as_aliases {
+            column_name()
+        } else {
+            format!(
+                "{}{}",
+                self.select_alias_prefix,
+                NumberAsAZ(self.selects.len())
+            )
+        }
+    }
+
     /// Add a field to select returning its index and alias
     pub fn select_field<F: Field, P: Path>(&mut self) -> (usize, String) {
         let path_id = P::add_to_context(self);
-        let alias = format!("{}", NumberAsAZ(self.selects.len()));
+        let alias = self.next_select_alias(|| F::NAME.to_string());
         let index = self.selects.len();
 
         self.selects.push(Select {
@@ -88,7 +180,7 @@ impl<'v> QueryContext<'v> {
         column: AggregatedColumn<I, R>,
     ) -> (usize, String) {
         let path_id = I::Path::add_to_context(self);
-        let alias = format!("{}", NumberAsAZ(self.selects.len()));
+        let alias = self.next_select_alias(|| format!("{}_{}", column.alias, I::Field::NAME));
         let index = self.selects.len();
 
         self.selects.push(Select {
@@ -132,6 +224,41 @@ impl<'v> QueryContext<'v> {
     }
 
     /// Add a field to order by
+    ///
+    /// The path is joined if it isn't already:
+    /// ```
+    /// # use rorm::internal::query_context::QueryContext;
+    /// # use rorm::fields::types::ForeignModel;
+    /// # use rorm::db::sql::ordering::Ordering;
+    /// # use rorm::prelude::*;
+    /// #[derive(Model)]
+    /// struct User {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///     #[rorm(max_length = 255)]
+    ///     name: String,
+    /// }
+    ///
+    /// #[derive(Model)]
+    /// struct Comment {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///     user: ForeignModel<User>,
+    /// }
+    ///
+    /// let mut ctx = QueryContext::new();
+    /// ctx.order_by_field::<__User_name, (__Comment_user, Comment)>(Ordering::Asc);
+    ///
+    /// let joins = ctx.get_joins();
+    /// assert_eq!(joins.len(), 1);
+    /// assert_eq!(joins[0].table_name, "user");
+    ///
+    /// let order_bys = ctx.get_order_bys();
+    /// assert_eq!(order_bys.len(), 1);
+    /// assert_eq!(order_bys[0].table_name, Some(joins[0].join_alias));
+    /// assert_eq!(order_bys[0].column_name, "name");
+    /// assert!(matches!(order_bys[0].ordering, Ordering::Asc));
+    /// ```
     pub fn order_by_field<F: Field, P: Path>(&mut self, ordering: Ordering) {
         let path_id = P::add_to_context(self);
         self.order_bys.push(OrderBy {
@@ -156,14 +283,19 @@ impl<'v> QueryContext<'v> {
             .iter()
             .map(
                 |Join {
+                     join_type,
                      table_name,
                      join_alias,
                      join_condition,
                  }| rorm_db::database::JoinTable {
-                    join_type: JoinType::Join,
+                    join_type: *join_type,
                     table_name,
                     join_alias: self.join_aliases.get(join_alias).unwrap(),
-                    join_condition: Cow::Owned(self.get_condition(*join_condition)),
+                    join_condition: Cow::Owned({
+                        let [head, tail @ ..] = join_condition;
+                        self.get_condition_inner(*head, &mut tail.iter().copied())
+                            .unwrap()
+                    }),
                 },
             )
             .collect()
@@ -191,6 +323,17 @@ impl<'v> QueryContext<'v> {
             .collect()
     }
 
+    /// Checks the values used by the added conditions to be valid
+    ///
+    /// # Errors
+    /// If a condition uses a [`Value::Invalid`],
+    /// its message is returned as error.
+    pub fn check_values(&self) -> Result<(), Error> {
+        self.values
+            .iter()
+            .try_for_each(|value| value.try_as_sql().map(drop))
+    }
+
     /// Retrieves the `rorm-sql` representation of a previously added `Condition`.
     ///
     /// # Errors
@@ -199,6 +342,9 @@ impl<'v> QueryContext<'v> {
     ///
     /// Since both cases are programmers' faults,
     /// you could consider [`QueryContext::get_condition`] which simply panics.
+    ///
+    /// The condition might also use a [`Value::Invalid`],
+    /// which should be reported using [`QueryContext::check_values`] before getting the condition.
     pub fn try_get_condition(
         &self,
         index: usize,
@@ -229,6 +375,43 @@ impl<'v> QueryContext<'v> {
             .expect("Got invalid condition index")
     }
 
+    /// Renders a previously added `Condition` as human-readable string
+    ///
+    /// This is independent of any sql dialect and meant for logging and debugging
+    /// i.e. to check what a composition of `and!` and `or!` produced.
+    ///
+    /// An invalid index is rendered as `<invalid condition: ...>` instead of panicking.
+    ///
+    /// ```
+    /// # use rorm::internal::query_context::QueryContext;
+    /// # use rorm::prelude::*;
+    /// # use rorm::{and, or};
+    /// #[derive(Model)]
+    /// struct User {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///     #[rorm(max_length = 255)]
+    ///     name: String,
+    ///     age: i16,
+    /// }
+    ///
+    /// let mut ctx = QueryContext::new();
+    /// let index = ctx.add_condition(&and![
+    ///     User.name.equals("alice"),
+    ///     or![User.age.less_than(18), User.age.greater_equals(65)],
+    /// ]);
+    /// assert_eq!(
+    ///     ctx.display_condition(index).to_string(),
+    ///     "(user.name = 'alice') AND ((user.age < 18) OR (user.age >= 65))"
+    /// );
+    /// ```
+    pub fn display_condition(&self, index: usize) -> DisplayCondition<'_, 'v> {
+        DisplayCondition {
+            context: self,
+            index,
+        }
+    }
+
     /// Shorthand for calling [`Self::get_condition`] on an optional index
     pub fn get_condition_opt(
         &self,
@@ -391,23 +574,67 @@ impl QueryContext<'_> {
         let path_id = <P::Step<F>>::id(self.base_path);
         if !self.join_aliases.contains_key(&path_id) {
             let parent_id = P::add_to_context(self);
-            let alias = format!("{}", NumberAsAZ(self.join_aliases.len()));
+            let alias = format!(
+                "{}{}",
+                self.join_alias_prefix,
+                NumberAsAZ(self.join_aliases.len())
+            );
             self.join_aliases.insert(path_id, alias);
             self.joins.push({
                 Join {
+                    join_type: if self.left_joins {
+                        JoinType::LeftJoin
+                    } else {
+                        JoinType::Join
+                    },
                     table_name: <<F as PathField<_>>::ChildField as Field>::Model::TABLE,
                     join_alias: path_id,
-                    join_condition: self.conditions.len(),
+                    join_condition: [
+                        FlatCondition::BinaryCondition(BinaryOperator::Equals),
+                        FlatCondition::Column(path_id, <F as PathField<_>>::ChildField::NAME),
+                        FlatCondition::Column(parent_id, <F as PathField<_>>::ParentField::NAME),
+                    ],
                 }
             });
-            self.conditions.extend([
-                FlatCondition::BinaryCondition(BinaryOperator::Equals),
-                FlatCondition::Column(path_id, <F as PathField<_>>::ChildField::NAME),
-                FlatCondition::Column(parent_id, <F as PathField<_>>::ParentField::NAME),
-            ]);
         }
         path_id
     }
+
+    /// Calls `function` returning the indexes and aliases of all columns it selects
+    pub(crate) fn collect_selects<T>(
+        &mut self,
+        function: impl FnOnce(&mut Self) -> T,
+    ) -> (T, Vec<(usize, String)>) {
+        let start = self.selects.len();
+        let result = function(self);
+        let selects = self.selects[start..]
+            .iter()
+            .enumerate()
+            .map(|(offset, select)| (start + offset, select.select_alias.clone()))
+            .collect();
+        (result, selects)
+    }
+
+    /// Use the selected columns' names as their aliases instead of generating unique ones
+    ///
+    /// An aggregated column's alias is the aggregation's name followed by the column's name,
+    /// for example `count_id`.
+    ///
+    /// This is used by [`query_raw`](crate::crud::raw::query_raw)
+    /// whose decoders have to find the columns of a hand-written statement.
+    pub(crate) fn use_column_names_as_aliases(&mut self) {
+        self.column_names_as_aliases = true;
+    }
+
+    /// Calls `function` with every join it adds being a `LEFT JOIN` instead of an inner one
+    ///
+    /// Paths which have been joined before are not affected.
+    pub(crate) fn with_left_joins<T>(&mut self, function: impl FnOnce(&mut Self) -> T) -> T {
+        let prev_left_joins = mem::replace(&mut self.left_joins, true);
+        let result = function(self);
+        self.left_joins = prev_left_joins;
+        result
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -420,9 +647,13 @@ struct Select {
 
 #[derive(Debug, Clone)]
 struct Join {
+    join_type: JoinType,
     table_name: &'static str,
     join_alias: PathId,
-    join_condition: usize,
+
+    /// Stored separately from [`QueryContext::conditions`]
+    /// because a path might be joined while a condition is being built.
+    join_condition: [FlatCondition; 3],
 }
 
 #[derive(Debug, Clone)]
@@ -433,23 +664,56 @@ struct OrderBy {
 }
 
 /// Adapter to display a number using the alphabet as digits
+///
+/// This uses bijective base-26 i.e. `0` is `a`, `25` is `z`, `26` is `aa` and so on.
+/// Every number produces a unique string.
 struct NumberAsAZ(usize);
 impl fmt::Display for NumberAsAZ {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        static ALPHABET: [char; 26] = [
-            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q',
-            'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
-        ];
+        static ALPHABET: [u8; 26] = *b"abcdefghijklmnopqrstuvwxyz";
+
+        // log26(usize::MAX) < 14
+        let mut buffer = [0u8; 14];
+        let mut start = buffer.len();
+
         let mut x = self.0;
-        match x {
-            0..26 => f.write_char(ALPHABET[x]),
-            _ => {
-                while x > 26 {
-                    f.write_char(ALPHABET[x % 26])?;
-                    x /= 26;
-                }
-                f.write_char(ALPHABET[x])
+        loop {
+            start -= 1;
+            buffer[start] = ALPHABET[x % 26];
+            x /= 26;
+            if x == 0 {
+                break;
             }
+            x -= 1;
+        }
+
+        // The buffer only contains ascii letters
+        f.write_str(std::str::from_utf8(&buffer[start..]).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::NumberAsAZ;
+
+    #[test]
+    fn number_as_az() {
+        assert_eq!(NumberAsAZ(0).to_string(), "a");
+        assert_eq!(NumberAsAZ(25).to_string(), "z");
+        assert_eq!(NumberAsAZ(26).to_string(), "aa");
+        assert_eq!(NumberAsAZ(27).to_string(), "ab");
+        assert_eq!(NumberAsAZ(26 * 27 - 1).to_string(), "zz");
+        assert_eq!(NumberAsAZ(26 * 27).to_string(), "aaa");
+        NumberAsAZ(usize::MAX).to_string();
+    }
+
+    #[test]
+    fn number_as_az_is_unique() {
+        let mut seen = HashSet::new();
+        for x in 0..10_000 {
+            assert!(seen.insert(NumberAsAZ(x).to_string()), "{x} collided");
         }
     }
 }
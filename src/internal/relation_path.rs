@@ -134,6 +134,31 @@ pub trait Path: 'static {
 }
 
 /// A unique identifier of a [`Path`]
+///
+/// Every step is part of the id.
+/// So a path visiting the same model several times (for example through a self-referencing foreign key)
+/// gets a distinct id and therefore a distinct join for each step:
+/// ```
+/// # use std::collections::HashSet;
+/// # use rorm::internal::query_context::QueryContext;
+/// # use rorm::fields::types::ForeignModel;
+/// # use rorm::prelude::*;
+/// #[derive(Model)]
+/// struct Comment {
+///     #[rorm(id)]
+///     id: i64,
+///
+///     parent: Option<ForeignModel<Comment>>,
+/// }
+///
+/// let mut ctx = QueryContext::new();
+/// ctx.add_condition(&Comment.parent.parent.id.equals(1));
+///
+/// let joins = ctx.get_joins();
+/// assert_eq!(joins.len(), 2);
+/// let aliases: HashSet<_> = joins.iter().map(|join| join.join_alias).collect();
+/// assert_eq!(aliases.len(), 2);
+/// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct PathId {
     hasher: djb2::Hasher,
@@ -0,0 +1,43 @@
+//! Helpers used by the code generated for `#[rorm(utoipa)]`
+
+use utoipa::openapi::ObjectBuilder;
+pub use utoipa::openapi::{RefOr, Schema};
+use utoipa::PartialSchema;
+pub use utoipa::ToSchema;
+
+use crate::fields::traits::FieldType;
+use crate::internal::field::SingleColumnField;
+
+/// Construct the builder for a model's or patch's schema
+pub fn new_object() -> ObjectBuilder {
+    ObjectBuilder::new()
+}
+
+/// Add a field as property to a model's or patch's schema
+///
+/// The field's `max_length` annotation is added to the property's schema
+/// and the property is marked as required unless the field is an `Option`.
+pub fn add_field<F>(object: ObjectBuilder, name: &str) -> ObjectBuilder
+where
+    F: SingleColumnField,
+    F::Type: PartialSchema,
+{
+    let mut schema = <F::Type as PartialSchema>::schema();
+    if let (RefOr::T(Schema::Object(schema)), Some(max_length)) =
+        (&mut schema, F::EFFECTIVE_ANNOTATION.max_length)
+    {
+        schema.max_length = Some(max_length.0 as usize);
+    }
+
+    let object = object.property(name, schema);
+    if <F::Type as FieldType>::is_option::<()>() {
+        object
+    } else {
+        object.required(name)
+    }
+}
+
+/// Finish a model's or patch's schema
+pub fn build_object(object: ObjectBuilder) -> RefOr<Schema> {
+    RefOr::T(Schema::Object(object.build()))
+}
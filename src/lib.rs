@@ -26,6 +26,8 @@ pub use linkme;
 pub use rorm_declaration::config;
 #[doc(hidden)] // used by macros
 pub use rorm_declaration::imr;
+#[doc(hidden)] // used by macros
+pub use serde;
 
 /// A prelude of common types, traits and derive macros that are used by `rorm`
 pub mod prelude {
@@ -37,7 +39,7 @@ pub mod prelude {
 }
 
 pub use crate::crud::delete::delete;
-pub use crate::crud::insert::insert;
+pub use crate::crud::insert::{insert, insert_many};
 pub use crate::crud::query::query;
 pub use crate::crud::update::update;
 
@@ -55,10 +57,36 @@ pub mod model;
 #[doc(hidden)]
 pub static MODELS: [fn() -> imr::Model] = [..];
 
+/// Collect all models in the Intermediate Model Representation.
+///
+/// This is the in-memory version of [`write_models`] for tools which want to process the models
+/// without a json round trip.
+///
+/// ```
+/// # use rorm::imr::InternalModelFormat;
+/// # use rorm::Model;
+/// #[derive(Model)]
+/// struct User {
+///     #[rorm(id)]
+///     id: i64,
+/// }
+///
+/// let models = rorm::collect_models();
+/// assert!(models.iter().any(|model| model.name == "user"));
+///
+/// let mut json = Vec::new();
+/// rorm::write_models(&mut json).unwrap();
+/// let imf: InternalModelFormat = serde_json::from_slice(&json).unwrap();
+/// assert_eq!(imf.models, models);
+/// ```
+pub fn collect_models() -> Vec<imr::Model> {
+    MODELS.iter().map(|func| func()).collect()
+}
+
 /// Write all models in the Intermediate Model Representation to a [writer](std::io::Write).
 pub fn write_models(writer: &mut impl std::io::Write) -> Result<(), serde_json::Error> {
     let imf = imr::InternalModelFormat {
-        models: MODELS.iter().map(|func| func()).collect(),
+        models: collect_models(),
     };
     serde_json::to_writer(writer, &imf)
 }
@@ -1,10 +1,13 @@
 //! This module holds traits and structs for working with models
 
+use std::marker::PhantomData;
+
+use rorm_db::sql::aggregation::SelectAggregator;
 use rorm_declaration::imr;
 
 use crate::conditions::{Binary, BinaryOperator, Column, Value};
 use crate::crud::decoder::Decoder;
-use crate::crud::selector::Selector;
+use crate::crud::selector::{AggregatedColumn, Selector};
 use crate::fields::proxy;
 use crate::internal::field::{Field, SingleColumnField};
 use crate::internal::hmr::{AsImr, Source};
@@ -95,17 +98,97 @@ pub trait Model: Patch<Model = Self> {
     /// As library user you probably won't need this. You might want to look at [`write_models`].
     ///
     /// [`write_models`]: crate::write_models
+    ///
+    /// `#[derive(Model)]` overrides this method to compute the representation only once
+    /// and return clones of it afterward (see [`CachedImr`]).
     fn get_imr() -> imr::Model {
-        let mut fields = Vec::new();
-        Self::push_fields_imr(&mut fields);
-        imr::Model {
-            name: Self::TABLE.to_string(),
-            fields,
-            source_defined_at: Some(Self::SOURCE.as_imr()),
+        build_imr::<Self>()
+    }
+
+    /// Count the rows like sql's `count(*)`
+    ///
+    /// Unlike [`FieldProxy::count`](proxy::FieldProxy::count) which only counts a column's non-null values,
+    /// this counts every row, even after joining related models.
+    /// (It is implemented by counting the primary key which is never null.)
+    ///
+    /// ```
+    /// # use rorm::crud::selector::Selector;
+    /// # use rorm::db::sql::aggregation::SelectAggregator;
+    /// # use rorm::internal::query_context::QueryContext;
+    /// # use rorm::prelude::*;
+    /// #[derive(Model)]
+    /// struct Post {
+    ///     #[rorm(id)]
+    ///     id: i64,
+    ///     published_at: Option<i64>,
+    /// }
+    ///
+    /// // `count(*)` vs. `count(published_at)` i.e. all posts vs. published ones
+    /// let selector = (Post::count_star(), Post.published_at.count());
+    ///
+    /// let mut ctx = QueryContext::new();
+    /// selector.select(&mut ctx);
+    /// let selects = ctx.get_selects();
+    /// assert_eq!(selects[0].column_name, "id");
+    /// assert_eq!(selects[1].column_name, "published_at");
+    /// assert!(selects
+    ///     .iter()
+    ///     .all(|select| matches!(select.aggregation, Some(SelectAggregator::Count))));
+    /// ```
+    fn count_star() -> AggregatedColumn<(Self::Primary, Self), i64> {
+        AggregatedColumn {
+            sql: SelectAggregator::Count,
+            alias: "count",
+            field: proxy::new(),
+            result: PhantomData,
         }
     }
 }
 
+/// Builds a model's intermediate representation
+///
+/// This is the uncached implementation of [`Model::get_imr`].
+///
+/// The fields are always listed in the order they are declared in,
+/// so the representation is stable between runs:
+/// ```
+/// # use rorm::Model;
+/// # use rorm::model::build_imr;
+/// #[derive(Model)]
+/// struct Post {
+///     title: rorm::fields::types::MaxStr<255>,
+///     #[rorm(id)]
+///     id: i64,
+///     body: rorm::fields::types::MaxStr<1024>,
+/// }
+///
+/// let names: Vec<_> = build_imr::<Post>()
+///     .fields
+///     .into_iter()
+///     .map(|field| field.name)
+///     .collect();
+/// assert_eq!(names, ["title", "id", "body"]);
+/// ```
+pub fn build_imr<M: Model>() -> imr::Model {
+    let mut fields = Vec::new();
+    M::push_fields_imr(&mut fields);
+    imr::Model {
+        name: M::TABLE.to_string(),
+        fields,
+        source_defined_at: Some(M::SOURCE.as_imr()),
+    }
+}
+
+/// A model whose intermediate representation is built once and stored in a static
+///
+/// Implemented by [`derive(Model)`](rorm_macro::Model) for every model
+/// which doesn't use `experimental_unregistered`.
+/// Its [`Model::get_imr`] returns clones of the stored representation.
+pub trait CachedImr: Model {
+    /// Returns the stored intermediate representation, building it on the first call
+    fn cached_imr() -> &'static imr::Model;
+}
+
 /// Expose a models' fields on the type level using indexes
 pub trait FieldByIndex<const INDEX: usize>: Model {
     /// The model's field at `INDEX`
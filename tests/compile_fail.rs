@@ -0,0 +1,6 @@
+//! Checks code which should be rejected at compile time
+
+#[test]
+fn compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/data/compile_fail/*.rs");
+}
@@ -0,0 +1,12 @@
+use rorm::Model;
+
+#[derive(Model)]
+pub struct Document {
+    #[rorm(id)]
+    pub id: i64,
+
+    #[rorm(max_length = 255, default = b"untitled")]
+    pub title: String,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use rorm::fields::types::{Cipher, Encrypted};
+use rorm::prelude::*;
+use rorm::Database;
+
+pub struct Xor;
+impl Cipher for Xor {
+    fn encrypt(plaintext: &[u8]) -> Vec<u8> {
+        plaintext.iter().map(|byte| byte ^ 0x2a).collect()
+    }
+    fn decrypt(ciphertext: &[u8]) -> Result<Vec<u8>, String> {
+        Ok(Self::encrypt(ciphertext))
+    }
+}
+
+#[derive(Model)]
+pub struct User {
+    #[rorm(id)]
+    pub id: i64,
+
+    pub email: Encrypted<String, Xor>,
+}
+
+pub async fn query_user(db: &Database, email: Encrypted<String, Xor>) {
+    let _ = rorm::query(db, User)
+        .condition(User.email.equals(email))
+        .all()
+        .await;
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use rorm::prelude::*;
+use rorm::Database;
+
+#[derive(Model)]
+pub struct User {
+    #[rorm(id)]
+    pub id: i64,
+}
+
+#[derive(Model)]
+pub struct Post {
+    #[rorm(id)]
+    pub id: i64,
+}
+
+pub async fn query_users(db: &Database) {
+    let _ = rorm::query(db, User).condition(Post.id.equals(1)).all().await;
+}
+
+fn main() {}
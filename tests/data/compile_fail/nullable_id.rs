@@ -0,0 +1,9 @@
+use rorm::Model;
+
+#[derive(Model)]
+pub struct Session {
+    #[rorm(id, nullable)]
+    pub id: i64,
+}
+
+fn main() {}
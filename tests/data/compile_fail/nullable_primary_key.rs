@@ -0,0 +1,9 @@
+use rorm::Model;
+
+#[derive(Model)]
+pub struct Session {
+    #[rorm(primary_key, nullable)]
+    pub id: i64,
+}
+
+fn main() {}
@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+
+use rorm::imr::Annotation;
+use rorm::internal::field::{Field, DEFAULT_MAX_LENGTH};
+use rorm::{field, Model};
+use url::Url;
+
+#[derive(Model)]
+pub struct Strings {
+    #[rorm(id)]
+    pub id: i64,
+
+    pub string: String,
+
+    pub optional_string: Option<String>,
+
+    pub url: Url,
+
+    pub path: PathBuf,
+
+    #[rorm(max_length = 16)]
+    pub explicit: String,
+}
+
+fn main() {
+    assert_eq!(DEFAULT_MAX_LENGTH, Some(255));
+
+    // The default is part of the annotations available at compile time
+    let [annotations] = <field!(Strings.string) as Field>::EFFECTIVE_ANNOTATIONS;
+    assert_eq!(
+        annotations.max_length.map(|max_length| max_length.0),
+        Some(255)
+    );
+    let [annotations] = <field!(Strings.url) as Field>::EFFECTIVE_ANNOTATIONS;
+    assert_eq!(
+        annotations.max_length.map(|max_length| max_length.0),
+        Some(255)
+    );
+    let [annotations] = <field!(Strings.explicit) as Field>::EFFECTIVE_ANNOTATIONS;
+    assert_eq!(
+        annotations.max_length.map(|max_length| max_length.0),
+        Some(16)
+    );
+
+    // ... and in the representation passed to the migrator
+    let imr = Strings::get_imr();
+    for (name, max_length) in [
+        ("string", 255),
+        ("optional_string", 255),
+        ("url", 255),
+        ("path", 255),
+        ("explicit", 16),
+    ] {
+        let field = imr.fields.iter().find(|field| field.name == name).unwrap();
+        assert!(
+            field
+                .annotations
+                .contains(&Annotation::MaxLength(max_length)),
+            "{name} should have a max_length of {max_length}"
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use rorm::model::{build_imr, CachedImr};
 use rorm::DbEnum;
 use rorm::Model;
 use rorm::Patch;
@@ -19,4 +20,14 @@ enum BasicEnum {
     Baz,
 }
 
-fn main() {}
+fn main() {
+    // The representation is built once and every call returns the same static
+    assert!(std::ptr::eq(
+        BasicModel::cached_imr(),
+        BasicModel::cached_imr()
+    ));
+
+    // `get_imr` is cached but still returns the same as a freshly built representation
+    assert_eq!(BasicModel::get_imr(), *BasicModel::cached_imr());
+    assert_eq!(BasicModel::get_imr(), build_imr::<BasicModel>());
+}
@@ -20,10 +20,12 @@ impl ::rorm::internal::field::Field for __BasicModel_id {
         default: None,
         index: None,
         max_length: None,
+        no_constraint: None,
         on_delete: None,
         on_update: None,
         primary_key: Some(::rorm::internal::hmr::annotations::PrimaryKey),
         unique: None,
+        explicit_nullable: None,
         nullable: false,
         foreign: None,
     };
@@ -76,6 +78,9 @@ impl ::rorm::model::Model for BasicModel {
     fn push_fields_imr(fields: &mut Vec<::rorm::imr::Field>) {
         ::rorm::internal::field::push_imr::<__BasicModel_id>(&mut *fields);
     }
+    fn get_imr() -> ::rorm::imr::Model {
+        <Self as ::rorm::model::CachedImr>::cached_imr().clone()
+    }
 }
 #[doc(hidden)]
 #[allow(non_camel_case_types)]
@@ -159,6 +164,12 @@ impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for &'a BasicModel {
         ::rorm::internal::patch::PatchCow::Borrowed(self)
     }
 }
+impl ::rorm::model::CachedImr for BasicModel {
+    fn cached_imr() -> &'static ::rorm::imr::Model {
+        static IMR: ::std::sync::OnceLock<::rorm::imr::Model> = ::std::sync::OnceLock::new();
+        IMR.get_or_init(::rorm::model::build_imr::<Self>)
+    }
+}
 const _: () = {
     #[::rorm::linkme::distributed_slice(::rorm::MODELS)]
     #[linkme(crate = ::rorm::linkme)]
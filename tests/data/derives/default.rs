@@ -0,0 +1,43 @@
+use rorm::imr::{Annotation, DefaultValue};
+use rorm::Model;
+use uuid::Uuid;
+
+#[derive(Model)]
+pub struct DefaultModel {
+    #[rorm(id)]
+    pub id: i64,
+
+    #[rorm(default = b"\x00\xc0\xff\xee")]
+    pub data: Vec<u8>,
+
+    #[rorm(default = "67e55044-10b1-426f-9247-bb680e5fe0c8")]
+    pub external_id: Uuid,
+}
+
+fn main() {
+    let model = DefaultModel::get_imr();
+    let default_of = |name: &str| {
+        let field = model
+            .fields
+            .iter()
+            .find(|field| field.name == name)
+            .unwrap();
+        field
+            .annotations
+            .iter()
+            .find_map(|annotation| match annotation {
+                Annotation::DefaultValue(default) => Some(default.clone()),
+                _ => None,
+            })
+            .unwrap()
+    };
+
+    assert_eq!(
+        default_of("data"),
+        DefaultValue::String("00c0ffee".to_string())
+    );
+    assert_eq!(
+        default_of("external_id"),
+        DefaultValue::String("67e55044-10b1-426f-9247-bb680e5fe0c8".to_string())
+    );
+}
@@ -0,0 +1,401 @@
+///rorm's representation of [`DefaultModel`]'s `id` field
+#[allow(non_camel_case_types)]
+pub struct __DefaultModel_id(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __DefaultModel_id {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __DefaultModel_id {}
+impl ::rorm::internal::field::Field for __DefaultModel_id {
+    type Type = i64;
+    type Model = DefaultModel;
+    const INDEX: usize = 0usize;
+    const NAME: &'static str = "id";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: Some(::rorm::internal::hmr::annotations::AutoIncrement),
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: Some(::rorm::internal::hmr::annotations::PrimaryKey),
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__DefaultModel_id>() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`DefaultModel`]'s `data` field
+#[allow(non_camel_case_types)]
+pub struct __DefaultModel_data(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __DefaultModel_data {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __DefaultModel_data {}
+impl ::rorm::internal::field::Field for __DefaultModel_data {
+    type Type = Vec<u8>;
+    type Model = DefaultModel;
+    const INDEX: usize = 1usize;
+    const NAME: &'static str = "data";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: Some(
+            ::rorm::internal::hmr::annotations::DefaultValue(
+                ::rorm::internal::hmr::annotations::DefaultValueData::Binary("00c0ffee"),
+            ),
+        ),
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__DefaultModel_data>() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`DefaultModel`]'s `external_id` field
+#[allow(non_camel_case_types)]
+pub struct __DefaultModel_external_id(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __DefaultModel_external_id {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __DefaultModel_external_id {}
+impl ::rorm::internal::field::Field for __DefaultModel_external_id {
+    type Type = Uuid;
+    type Model = DefaultModel;
+    const INDEX: usize = 2usize;
+    const NAME: &'static str = "external_id";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: Some(
+            ::rorm::internal::hmr::annotations::DefaultValue(
+                ::rorm::internal::hmr::annotations::DefaultValueData::String(
+                    "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                ),
+            ),
+        ),
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__DefaultModel_external_id>() {
+        panic!("{}", err.as_str());
+    }
+};
+///[`DefaultModel`]'s [`Fields`](::rorm::model::Model::Fields) struct.
+#[allow(non_camel_case_types)]
+pub struct __DefaultModel_Fields_Struct<Path: ::rorm::internal::relation_path::Path> {
+    ///[`DefaultModel`]'s `id` field
+    pub id: ::rorm::fields::proxy::FieldProxy<(__DefaultModel_id, Path)>,
+    ///[`DefaultModel`]'s `data` field
+    pub data: ::rorm::fields::proxy::FieldProxy<(__DefaultModel_data, Path)>,
+    ///[`DefaultModel`]'s `external_id` field
+    pub external_id: ::rorm::fields::proxy::FieldProxy<
+        (__DefaultModel_external_id, Path),
+    >,
+}
+impl<Path: ::rorm::internal::relation_path::Path> ::rorm::model::ConstNew
+for __DefaultModel_Fields_Struct<Path> {
+    const NEW: Self = Self {
+        id: ::rorm::fields::proxy::new(),
+        data: ::rorm::fields::proxy::new(),
+        external_id: ::rorm::fields::proxy::new(),
+    };
+    const REF: &'static Self = &Self::NEW;
+}
+impl ::std::ops::Deref for __DefaultModel_ValueSpaceImpl {
+    type Target = <DefaultModel as ::rorm::Model>::Fields<DefaultModel>;
+    fn deref(&self) -> &Self::Target {
+        ::rorm::model::ConstNew::REF
+    }
+}
+impl ::rorm::model::Model for DefaultModel {
+    type Primary = __DefaultModel_id;
+    type Fields<P: ::rorm::internal::relation_path::Path> = __DefaultModel_Fields_Struct<
+        P,
+    >;
+    const F: __DefaultModel_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const FIELDS: __DefaultModel_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const TABLE: &'static str = "defaultmodel";
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn push_fields_imr(fields: &mut Vec<::rorm::imr::Field>) {
+        ::rorm::internal::field::push_imr::<__DefaultModel_id>(&mut *fields);
+        ::rorm::internal::field::push_imr::<__DefaultModel_data>(&mut *fields);
+        ::rorm::internal::field::push_imr::<__DefaultModel_external_id>(&mut *fields);
+    }
+    fn get_imr() -> ::rorm::imr::Model {
+        <Self as ::rorm::model::CachedImr>::cached_imr().clone()
+    }
+}
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub enum __DefaultModel_ValueSpaceImpl {
+    DefaultModel,
+    #[allow(dead_code)]
+    #[doc(hidden)]
+    __DefaultModel_ValueSpaceImplMarker(::std::marker::PhantomData<DefaultModel>),
+}
+pub use __DefaultModel_ValueSpaceImpl::*;
+pub struct __DefaultModel_Decoder {
+    id: <i64 as ::rorm::fields::traits::FieldType>::Decoder,
+    data: <Vec<u8> as ::rorm::fields::traits::FieldType>::Decoder,
+    external_id: <Uuid as ::rorm::fields::traits::FieldType>::Decoder,
+}
+impl ::rorm::crud::selector::Selector for __DefaultModel_ValueSpaceImpl {
+    type Result = DefaultModel;
+    type Model = DefaultModel;
+    type Decoder = __DefaultModel_Decoder;
+    const INSERT_COMPATIBLE: bool = true;
+    fn select(
+        self,
+        ctx: &mut ::rorm::internal::query_context::QueryContext,
+    ) -> Self::Decoder {
+        __DefaultModel_Decoder {
+            id: <DefaultModel as ::rorm::model::Model>::FIELDS.id.select(&mut *ctx),
+            data: <DefaultModel as ::rorm::model::Model>::FIELDS.data.select(&mut *ctx),
+            external_id: <DefaultModel as ::rorm::model::Model>::FIELDS
+                .external_id
+                .select(&mut *ctx),
+        }
+    }
+}
+impl ::std::default::Default for __DefaultModel_ValueSpaceImpl {
+    fn default() -> Self {
+        Self::DefaultModel
+    }
+}
+impl ::rorm::crud::decoder::Decoder for __DefaultModel_Decoder {
+    type Result = DefaultModel;
+    fn by_name<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(DefaultModel {
+            id: self.id.by_name(row)?,
+            data: self.data.by_name(row)?,
+            external_id: self.external_id.by_name(row)?,
+        })
+    }
+    fn by_index<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(DefaultModel {
+            id: self.id.by_index(row)?,
+            data: self.data.by_index(row)?,
+            external_id: self.external_id.by_index(row)?,
+        })
+    }
+}
+impl ::rorm::model::Patch for DefaultModel {
+    type Model = DefaultModel;
+    type ValueSpaceImpl = __DefaultModel_ValueSpaceImpl;
+    type Decoder = __DefaultModel_Decoder;
+    fn push_columns(columns: &mut Vec<&'static str>) {
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .id
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .data
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .external_id
+                }),
+            );
+    }
+    fn push_references<'a>(&'a self, values: &mut Vec<::rorm::conditions::Value<'a>>) {
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.id));
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.data));
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.external_id));
+    }
+    fn push_values(self, values: &mut Vec<::rorm::conditions::Value>) {
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.id));
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.data));
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.external_id));
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for DefaultModel {
+    type Patch = DefaultModel;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, DefaultModel> {
+        ::rorm::internal::patch::PatchCow::Owned(self)
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for &'a DefaultModel {
+    type Patch = DefaultModel;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, DefaultModel> {
+        ::rorm::internal::patch::PatchCow::Borrowed(self)
+    }
+}
+impl ::rorm::model::CachedImr for DefaultModel {
+    fn cached_imr() -> &'static ::rorm::imr::Model {
+        static IMR: ::std::sync::OnceLock<::rorm::imr::Model> = ::std::sync::OnceLock::new();
+        IMR.get_or_init(::rorm::model::build_imr::<Self>)
+    }
+}
+const _: () = {
+    #[::rorm::linkme::distributed_slice(::rorm::MODELS)]
+    #[linkme(crate = ::rorm::linkme)]
+    static __get_imr: fn() -> ::rorm::imr::Model = <DefaultModel as ::rorm::model::Model>::get_imr;
+    let mut count_auto_increment = 0;
+    let mut annos_slice = <__DefaultModel_id as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__DefaultModel_data as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__DefaultModel_external_id as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    assert!(
+        count_auto_increment <= 1, "\"auto_increment\" can only be set once per model"
+    );
+};
+impl ::rorm::model::FieldByIndex<{ 0usize }> for DefaultModel {
+    type Field = __DefaultModel_id;
+}
+impl ::rorm::model::GetField<__DefaultModel_id> for DefaultModel {
+    fn get_field(self) -> i64 {
+        self.id
+    }
+    fn borrow_field(&self) -> &i64 {
+        &self.id
+    }
+    fn borrow_field_mut(&mut self) -> &mut i64 {
+        &mut self.id
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 1usize }> for DefaultModel {
+    type Field = __DefaultModel_data;
+}
+impl ::rorm::model::GetField<__DefaultModel_data> for DefaultModel {
+    fn get_field(self) -> Vec<u8> {
+        self.data
+    }
+    fn borrow_field(&self) -> &Vec<u8> {
+        &self.data
+    }
+    fn borrow_field_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.data
+    }
+}
+impl ::rorm::model::UpdateField<__DefaultModel_data> for DefaultModel {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut Vec<u8>) -> T,
+    ) -> T {
+        update(&self.id, &mut self.data)
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 2usize }> for DefaultModel {
+    type Field = __DefaultModel_external_id;
+}
+impl ::rorm::model::GetField<__DefaultModel_external_id> for DefaultModel {
+    fn get_field(self) -> Uuid {
+        self.external_id
+    }
+    fn borrow_field(&self) -> &Uuid {
+        &self.external_id
+    }
+    fn borrow_field_mut(&mut self) -> &mut Uuid {
+        &mut self.external_id
+    }
+}
+impl ::rorm::model::UpdateField<__DefaultModel_external_id> for DefaultModel {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut Uuid) -> T,
+    ) -> T {
+        update(&self.id, &mut self.external_id)
+    }
+}
@@ -23,10 +23,12 @@ for __Generic_id<X> {
         default: None,
         index: None,
         max_length: None,
+        no_constraint: None,
         on_delete: None,
         on_update: None,
         primary_key: Some(::rorm::internal::hmr::annotations::PrimaryKey),
         unique: None,
+        explicit_nullable: None,
         nullable: false,
         foreign: None,
     };
@@ -64,10 +66,12 @@ for __Generic_x<X> {
         default: None,
         index: None,
         max_length: None,
+        no_constraint: None,
         on_delete: None,
         on_update: None,
         primary_key: None,
         unique: None,
+        explicit_nullable: None,
         nullable: false,
         foreign: None,
     };
@@ -20,10 +20,12 @@ impl ::rorm::internal::field::Field for __Unregistered_id {
         default: None,
         index: None,
         max_length: None,
+        no_constraint: None,
         on_delete: None,
         on_update: None,
         primary_key: Some(::rorm::internal::hmr::annotations::PrimaryKey),
         unique: None,
+        explicit_nullable: None,
         nullable: false,
         foreign: None,
     };
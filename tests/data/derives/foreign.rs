@@ -0,0 +1,18 @@
+use rorm::fields::types::ForeignModel;
+
+#[derive(rorm::Model)]
+pub struct Referenced {
+    #[rorm(id)]
+    pub id: i64,
+}
+
+#[derive(rorm::Model)]
+pub struct SoftReference {
+    #[rorm(id)]
+    pub id: i64,
+
+    #[rorm(foreign(no_constraint))]
+    pub referenced: ForeignModel<Referenced>,
+}
+
+fn main() {}
@@ -0,0 +1,203 @@
+///rorm's representation of [`Referenced`]'s `id` field
+#[allow(non_camel_case_types)]
+pub struct __Referenced_id(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __Referenced_id {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __Referenced_id {}
+impl ::rorm::internal::field::Field for __Referenced_id {
+    type Type = i64;
+    type Model = Referenced;
+    const INDEX: usize = 0usize;
+    const NAME: &'static str = "id";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: Some(::rorm::internal::hmr::annotations::AutoIncrement),
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: Some(::rorm::internal::hmr::annotations::PrimaryKey),
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__Referenced_id>() {
+        panic!("{}", err.as_str());
+    }
+};
+///[`Referenced`]'s [`Fields`](::rorm::model::Model::Fields) struct.
+#[allow(non_camel_case_types)]
+pub struct __Referenced_Fields_Struct<Path: ::rorm::internal::relation_path::Path> {
+    ///[`Referenced`]'s `id` field
+    pub id: ::rorm::fields::proxy::FieldProxy<(__Referenced_id, Path)>,
+}
+impl<Path: ::rorm::internal::relation_path::Path> ::rorm::model::ConstNew
+for __Referenced_Fields_Struct<Path> {
+    const NEW: Self = Self {
+        id: ::rorm::fields::proxy::new(),
+    };
+    const REF: &'static Self = &Self::NEW;
+}
+impl ::std::ops::Deref for __Referenced_ValueSpaceImpl {
+    type Target = <Referenced as ::rorm::Model>::Fields<Referenced>;
+    fn deref(&self) -> &Self::Target {
+        ::rorm::model::ConstNew::REF
+    }
+}
+impl ::rorm::model::Model for Referenced {
+    type Primary = __Referenced_id;
+    type Fields<P: ::rorm::internal::relation_path::Path> = __Referenced_Fields_Struct<
+        P,
+    >;
+    const F: __Referenced_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const FIELDS: __Referenced_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const TABLE: &'static str = "referenced";
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn push_fields_imr(fields: &mut Vec<::rorm::imr::Field>) {
+        ::rorm::internal::field::push_imr::<__Referenced_id>(&mut *fields);
+    }
+    fn get_imr() -> ::rorm::imr::Model {
+        <Self as ::rorm::model::CachedImr>::cached_imr().clone()
+    }
+}
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub enum __Referenced_ValueSpaceImpl {
+    Referenced,
+    #[allow(dead_code)]
+    #[doc(hidden)]
+    __Referenced_ValueSpaceImplMarker(::std::marker::PhantomData<Referenced>),
+}
+pub use __Referenced_ValueSpaceImpl::*;
+pub struct __Referenced_Decoder {
+    id: <i64 as ::rorm::fields::traits::FieldType>::Decoder,
+}
+impl ::rorm::crud::selector::Selector for __Referenced_ValueSpaceImpl {
+    type Result = Referenced;
+    type Model = Referenced;
+    type Decoder = __Referenced_Decoder;
+    const INSERT_COMPATIBLE: bool = true;
+    fn select(
+        self,
+        ctx: &mut ::rorm::internal::query_context::QueryContext,
+    ) -> Self::Decoder {
+        __Referenced_Decoder {
+            id: <Referenced as ::rorm::model::Model>::FIELDS.id.select(&mut *ctx),
+        }
+    }
+}
+impl ::std::default::Default for __Referenced_ValueSpaceImpl {
+    fn default() -> Self {
+        Self::Referenced
+    }
+}
+impl ::rorm::crud::decoder::Decoder for __Referenced_Decoder {
+    type Result = Referenced;
+    fn by_name<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(Referenced {
+            id: self.id.by_name(row)?,
+        })
+    }
+    fn by_index<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(Referenced {
+            id: self.id.by_index(row)?,
+        })
+    }
+}
+impl ::rorm::model::Patch for Referenced {
+    type Model = Referenced;
+    type ValueSpaceImpl = __Referenced_ValueSpaceImpl;
+    type Decoder = __Referenced_Decoder;
+    fn push_columns(columns: &mut Vec<&'static str>) {
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .id
+                }),
+            );
+    }
+    fn push_references<'a>(&'a self, values: &mut Vec<::rorm::conditions::Value<'a>>) {
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.id));
+    }
+    fn push_values(self, values: &mut Vec<::rorm::conditions::Value>) {
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.id));
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for Referenced {
+    type Patch = Referenced;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, Referenced> {
+        ::rorm::internal::patch::PatchCow::Owned(self)
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for &'a Referenced {
+    type Patch = Referenced;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, Referenced> {
+        ::rorm::internal::patch::PatchCow::Borrowed(self)
+    }
+}
+impl ::rorm::model::CachedImr for Referenced {
+    fn cached_imr() -> &'static ::rorm::imr::Model {
+        static IMR: ::std::sync::OnceLock<::rorm::imr::Model> = ::std::sync::OnceLock::new();
+        IMR.get_or_init(::rorm::model::build_imr::<Self>)
+    }
+}
+const _: () = {
+    #[::rorm::linkme::distributed_slice(::rorm::MODELS)]
+    #[linkme(crate = ::rorm::linkme)]
+    static __get_imr: fn() -> ::rorm::imr::Model = <Referenced as ::rorm::model::Model>::get_imr;
+    let mut count_auto_increment = 0;
+    let mut annos_slice = <__Referenced_id as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    assert!(
+        count_auto_increment <= 1, "\"auto_increment\" can only be set once per model"
+    );
+};
+impl ::rorm::model::FieldByIndex<{ 0usize }> for Referenced {
+    type Field = __Referenced_id;
+}
+impl ::rorm::model::GetField<__Referenced_id> for Referenced {
+    fn get_field(self) -> i64 {
+        self.id
+    }
+    fn borrow_field(&self) -> &i64 {
+        &self.id
+    }
+    fn borrow_field_mut(&mut self) -> &mut i64 {
+        &mut self.id
+    }
+}
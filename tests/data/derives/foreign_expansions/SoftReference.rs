@@ -0,0 +1,299 @@
+///rorm's representation of [`SoftReference`]'s `id` field
+#[allow(non_camel_case_types)]
+pub struct __SoftReference_id(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __SoftReference_id {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __SoftReference_id {}
+impl ::rorm::internal::field::Field for __SoftReference_id {
+    type Type = i64;
+    type Model = SoftReference;
+    const INDEX: usize = 0usize;
+    const NAME: &'static str = "id";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: Some(::rorm::internal::hmr::annotations::AutoIncrement),
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: Some(::rorm::internal::hmr::annotations::PrimaryKey),
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__SoftReference_id>() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`SoftReference`]'s `referenced` field
+#[allow(non_camel_case_types)]
+pub struct __SoftReference_referenced(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __SoftReference_referenced {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __SoftReference_referenced {}
+impl ::rorm::internal::field::Field for __SoftReference_referenced {
+    type Type = ForeignModel<Referenced>;
+    type Model = SoftReference;
+    const INDEX: usize = 1usize;
+    const NAME: &'static str = "referenced";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: Some(::rorm::internal::hmr::annotations::NoConstraint),
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__SoftReference_referenced>() {
+        panic!("{}", err.as_str());
+    }
+};
+///[`SoftReference`]'s [`Fields`](::rorm::model::Model::Fields) struct.
+#[allow(non_camel_case_types)]
+pub struct __SoftReference_Fields_Struct<Path: ::rorm::internal::relation_path::Path> {
+    ///[`SoftReference`]'s `id` field
+    pub id: ::rorm::fields::proxy::FieldProxy<(__SoftReference_id, Path)>,
+    ///[`SoftReference`]'s `referenced` field
+    pub referenced: ::rorm::fields::proxy::FieldProxy<
+        (__SoftReference_referenced, Path),
+    >,
+}
+impl<Path: ::rorm::internal::relation_path::Path> ::rorm::model::ConstNew
+for __SoftReference_Fields_Struct<Path> {
+    const NEW: Self = Self {
+        id: ::rorm::fields::proxy::new(),
+        referenced: ::rorm::fields::proxy::new(),
+    };
+    const REF: &'static Self = &Self::NEW;
+}
+impl ::std::ops::Deref for __SoftReference_ValueSpaceImpl {
+    type Target = <SoftReference as ::rorm::Model>::Fields<SoftReference>;
+    fn deref(&self) -> &Self::Target {
+        ::rorm::model::ConstNew::REF
+    }
+}
+impl ::rorm::model::Model for SoftReference {
+    type Primary = __SoftReference_id;
+    type Fields<P: ::rorm::internal::relation_path::Path> = __SoftReference_Fields_Struct<
+        P,
+    >;
+    const F: __SoftReference_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const FIELDS: __SoftReference_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const TABLE: &'static str = "softreference";
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn push_fields_imr(fields: &mut Vec<::rorm::imr::Field>) {
+        ::rorm::internal::field::push_imr::<__SoftReference_id>(&mut *fields);
+        ::rorm::internal::field::push_imr::<__SoftReference_referenced>(&mut *fields);
+    }
+    fn get_imr() -> ::rorm::imr::Model {
+        <Self as ::rorm::model::CachedImr>::cached_imr().clone()
+    }
+}
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub enum __SoftReference_ValueSpaceImpl {
+    SoftReference,
+    #[allow(dead_code)]
+    #[doc(hidden)]
+    __SoftReference_ValueSpaceImplMarker(::std::marker::PhantomData<SoftReference>),
+}
+pub use __SoftReference_ValueSpaceImpl::*;
+pub struct __SoftReference_Decoder {
+    id: <i64 as ::rorm::fields::traits::FieldType>::Decoder,
+    referenced: <ForeignModel<Referenced> as ::rorm::fields::traits::FieldType>::Decoder,
+}
+impl ::rorm::crud::selector::Selector for __SoftReference_ValueSpaceImpl {
+    type Result = SoftReference;
+    type Model = SoftReference;
+    type Decoder = __SoftReference_Decoder;
+    const INSERT_COMPATIBLE: bool = true;
+    fn select(
+        self,
+        ctx: &mut ::rorm::internal::query_context::QueryContext,
+    ) -> Self::Decoder {
+        __SoftReference_Decoder {
+            id: <SoftReference as ::rorm::model::Model>::FIELDS.id.select(&mut *ctx),
+            referenced: <SoftReference as ::rorm::model::Model>::FIELDS
+                .referenced
+                .select(&mut *ctx),
+        }
+    }
+}
+impl ::std::default::Default for __SoftReference_ValueSpaceImpl {
+    fn default() -> Self {
+        Self::SoftReference
+    }
+}
+impl ::rorm::crud::decoder::Decoder for __SoftReference_Decoder {
+    type Result = SoftReference;
+    fn by_name<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(SoftReference {
+            id: self.id.by_name(row)?,
+            referenced: self.referenced.by_name(row)?,
+        })
+    }
+    fn by_index<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(SoftReference {
+            id: self.id.by_index(row)?,
+            referenced: self.referenced.by_index(row)?,
+        })
+    }
+}
+impl ::rorm::model::Patch for SoftReference {
+    type Model = SoftReference;
+    type ValueSpaceImpl = __SoftReference_ValueSpaceImpl;
+    type Decoder = __SoftReference_Decoder;
+    fn push_columns(columns: &mut Vec<&'static str>) {
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .id
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .referenced
+                }),
+            );
+    }
+    fn push_references<'a>(&'a self, values: &mut Vec<::rorm::conditions::Value<'a>>) {
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.id));
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.referenced));
+    }
+    fn push_values(self, values: &mut Vec<::rorm::conditions::Value>) {
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.id));
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.referenced));
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for SoftReference {
+    type Patch = SoftReference;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, SoftReference> {
+        ::rorm::internal::patch::PatchCow::Owned(self)
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for &'a SoftReference {
+    type Patch = SoftReference;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, SoftReference> {
+        ::rorm::internal::patch::PatchCow::Borrowed(self)
+    }
+}
+impl ::rorm::model::CachedImr for SoftReference {
+    fn cached_imr() -> &'static ::rorm::imr::Model {
+        static IMR: ::std::sync::OnceLock<::rorm::imr::Model> = ::std::sync::OnceLock::new();
+        IMR.get_or_init(::rorm::model::build_imr::<Self>)
+    }
+}
+const _: () = {
+    #[::rorm::linkme::distributed_slice(::rorm::MODELS)]
+    #[linkme(crate = ::rorm::linkme)]
+    static __get_imr: fn() -> ::rorm::imr::Model = <SoftReference as ::rorm::model::Model>::get_imr;
+    let mut count_auto_increment = 0;
+    let mut annos_slice = <__SoftReference_id as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__SoftReference_referenced as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    assert!(
+        count_auto_increment <= 1, "\"auto_increment\" can only be set once per model"
+    );
+};
+impl ::rorm::model::FieldByIndex<{ 0usize }> for SoftReference {
+    type Field = __SoftReference_id;
+}
+impl ::rorm::model::GetField<__SoftReference_id> for SoftReference {
+    fn get_field(self) -> i64 {
+        self.id
+    }
+    fn borrow_field(&self) -> &i64 {
+        &self.id
+    }
+    fn borrow_field_mut(&mut self) -> &mut i64 {
+        &mut self.id
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 1usize }> for SoftReference {
+    type Field = __SoftReference_referenced;
+}
+impl ::rorm::model::GetField<__SoftReference_referenced> for SoftReference {
+    fn get_field(self) -> ForeignModel<Referenced> {
+        self.referenced
+    }
+    fn borrow_field(&self) -> &ForeignModel<Referenced> {
+        &self.referenced
+    }
+    fn borrow_field_mut(&mut self) -> &mut ForeignModel<Referenced> {
+        &mut self.referenced
+    }
+}
+impl ::rorm::model::UpdateField<__SoftReference_referenced> for SoftReference {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut ForeignModel<Referenced>) -> T,
+    ) -> T {
+        update(&self.id, &mut self.referenced)
+    }
+}
@@ -0,0 +1,38 @@
+use rorm::imr::Annotation;
+use rorm::Model;
+
+#[derive(Model)]
+pub struct NullableModel {
+    #[rorm(id)]
+    pub id: i64,
+
+    pub implicit_not_null: i64,
+
+    pub implicit_nullable: Option<i64>,
+
+    /// Already nullable in the database, but not yet in rust
+    #[rorm(nullable)]
+    pub explicit_nullable: i64,
+
+    /// Not yet non-nullable in the database, but already in rust
+    #[rorm(not_null)]
+    pub explicit_not_null: Option<i64>,
+}
+
+fn main() {
+    let model = NullableModel::get_imr();
+    let not_null = |name: &str| {
+        model
+            .fields
+            .iter()
+            .find(|field| field.name == name)
+            .unwrap()
+            .annotations
+            .contains(&Annotation::NotNull)
+    };
+
+    assert!(not_null("implicit_not_null"));
+    assert!(!not_null("implicit_nullable"));
+    assert!(!not_null("explicit_nullable"));
+    assert!(not_null("explicit_not_null"));
+}
@@ -0,0 +1,627 @@
+///rorm's representation of [`NullableModel`]'s `id` field
+#[allow(non_camel_case_types)]
+pub struct __NullableModel_id(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __NullableModel_id {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __NullableModel_id {}
+impl ::rorm::internal::field::Field for __NullableModel_id {
+    type Type = i64;
+    type Model = NullableModel;
+    const INDEX: usize = 0usize;
+    const NAME: &'static str = "id";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: Some(::rorm::internal::hmr::annotations::AutoIncrement),
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: Some(::rorm::internal::hmr::annotations::PrimaryKey),
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__NullableModel_id>() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`NullableModel`]'s `implicit_not_null` field
+#[allow(non_camel_case_types)]
+pub struct __NullableModel_implicit_not_null(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __NullableModel_implicit_not_null {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __NullableModel_implicit_not_null {}
+impl ::rorm::internal::field::Field for __NullableModel_implicit_not_null {
+    type Type = i64;
+    type Model = NullableModel;
+    const INDEX: usize = 1usize;
+    const NAME: &'static str = "implicit_not_null";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<
+        __NullableModel_implicit_not_null,
+    >() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`NullableModel`]'s `implicit_nullable` field
+#[allow(non_camel_case_types)]
+pub struct __NullableModel_implicit_nullable(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __NullableModel_implicit_nullable {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __NullableModel_implicit_nullable {}
+impl ::rorm::internal::field::Field for __NullableModel_implicit_nullable {
+    type Type = Option<i64>;
+    type Model = NullableModel;
+    const INDEX: usize = 2usize;
+    const NAME: &'static str = "implicit_nullable";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<
+        __NullableModel_implicit_nullable,
+    >() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`NullableModel`]'s `explicit_nullable` field
+#[allow(non_camel_case_types)]
+pub struct __NullableModel_explicit_nullable(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __NullableModel_explicit_nullable {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __NullableModel_explicit_nullable {}
+impl ::rorm::internal::field::Field for __NullableModel_explicit_nullable {
+    type Type = i64;
+    type Model = NullableModel;
+    const INDEX: usize = 3usize;
+    const NAME: &'static str = "explicit_nullable";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: Some(true),
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<
+        __NullableModel_explicit_nullable,
+    >() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`NullableModel`]'s `explicit_not_null` field
+#[allow(non_camel_case_types)]
+pub struct __NullableModel_explicit_not_null(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __NullableModel_explicit_not_null {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __NullableModel_explicit_not_null {}
+impl ::rorm::internal::field::Field for __NullableModel_explicit_not_null {
+    type Type = Option<i64>;
+    type Model = NullableModel;
+    const INDEX: usize = 4usize;
+    const NAME: &'static str = "explicit_not_null";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: Some(false),
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<
+        __NullableModel_explicit_not_null,
+    >() {
+        panic!("{}", err.as_str());
+    }
+};
+///[`NullableModel`]'s [`Fields`](::rorm::model::Model::Fields) struct.
+#[allow(non_camel_case_types)]
+pub struct __NullableModel_Fields_Struct<Path: ::rorm::internal::relation_path::Path> {
+    ///[`NullableModel`]'s `id` field
+    pub id: ::rorm::fields::proxy::FieldProxy<(__NullableModel_id, Path)>,
+    ///[`NullableModel`]'s `implicit_not_null` field
+    pub implicit_not_null: ::rorm::fields::proxy::FieldProxy<
+        (__NullableModel_implicit_not_null, Path),
+    >,
+    ///[`NullableModel`]'s `implicit_nullable` field
+    pub implicit_nullable: ::rorm::fields::proxy::FieldProxy<
+        (__NullableModel_implicit_nullable, Path),
+    >,
+    ///[`NullableModel`]'s `explicit_nullable` field
+    pub explicit_nullable: ::rorm::fields::proxy::FieldProxy<
+        (__NullableModel_explicit_nullable, Path),
+    >,
+    ///[`NullableModel`]'s `explicit_not_null` field
+    pub explicit_not_null: ::rorm::fields::proxy::FieldProxy<
+        (__NullableModel_explicit_not_null, Path),
+    >,
+}
+impl<Path: ::rorm::internal::relation_path::Path> ::rorm::model::ConstNew
+for __NullableModel_Fields_Struct<Path> {
+    const NEW: Self = Self {
+        id: ::rorm::fields::proxy::new(),
+        implicit_not_null: ::rorm::fields::proxy::new(),
+        implicit_nullable: ::rorm::fields::proxy::new(),
+        explicit_nullable: ::rorm::fields::proxy::new(),
+        explicit_not_null: ::rorm::fields::proxy::new(),
+    };
+    const REF: &'static Self = &Self::NEW;
+}
+impl ::std::ops::Deref for __NullableModel_ValueSpaceImpl {
+    type Target = <NullableModel as ::rorm::Model>::Fields<NullableModel>;
+    fn deref(&self) -> &Self::Target {
+        ::rorm::model::ConstNew::REF
+    }
+}
+impl ::rorm::model::Model for NullableModel {
+    type Primary = __NullableModel_id;
+    type Fields<P: ::rorm::internal::relation_path::Path> = __NullableModel_Fields_Struct<
+        P,
+    >;
+    const F: __NullableModel_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const FIELDS: __NullableModel_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const TABLE: &'static str = "nullablemodel";
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn push_fields_imr(fields: &mut Vec<::rorm::imr::Field>) {
+        ::rorm::internal::field::push_imr::<__NullableModel_id>(&mut *fields);
+        ::rorm::internal::field::push_imr::<
+            __NullableModel_implicit_not_null,
+        >(&mut *fields);
+        ::rorm::internal::field::push_imr::<
+            __NullableModel_implicit_nullable,
+        >(&mut *fields);
+        ::rorm::internal::field::push_imr::<
+            __NullableModel_explicit_nullable,
+        >(&mut *fields);
+        ::rorm::internal::field::push_imr::<
+            __NullableModel_explicit_not_null,
+        >(&mut *fields);
+    }
+    fn get_imr() -> ::rorm::imr::Model {
+        <Self as ::rorm::model::CachedImr>::cached_imr().clone()
+    }
+}
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub enum __NullableModel_ValueSpaceImpl {
+    NullableModel,
+    #[allow(dead_code)]
+    #[doc(hidden)]
+    __NullableModel_ValueSpaceImplMarker(::std::marker::PhantomData<NullableModel>),
+}
+pub use __NullableModel_ValueSpaceImpl::*;
+pub struct __NullableModel_Decoder {
+    id: <i64 as ::rorm::fields::traits::FieldType>::Decoder,
+    implicit_not_null: <i64 as ::rorm::fields::traits::FieldType>::Decoder,
+    implicit_nullable: <Option<i64> as ::rorm::fields::traits::FieldType>::Decoder,
+    explicit_nullable: <i64 as ::rorm::fields::traits::FieldType>::Decoder,
+    explicit_not_null: <Option<i64> as ::rorm::fields::traits::FieldType>::Decoder,
+}
+impl ::rorm::crud::selector::Selector for __NullableModel_ValueSpaceImpl {
+    type Result = NullableModel;
+    type Model = NullableModel;
+    type Decoder = __NullableModel_Decoder;
+    const INSERT_COMPATIBLE: bool = true;
+    fn select(
+        self,
+        ctx: &mut ::rorm::internal::query_context::QueryContext,
+    ) -> Self::Decoder {
+        __NullableModel_Decoder {
+            id: <NullableModel as ::rorm::model::Model>::FIELDS.id.select(&mut *ctx),
+            implicit_not_null: <NullableModel as ::rorm::model::Model>::FIELDS
+                .implicit_not_null
+                .select(&mut *ctx),
+            implicit_nullable: <NullableModel as ::rorm::model::Model>::FIELDS
+                .implicit_nullable
+                .select(&mut *ctx),
+            explicit_nullable: <NullableModel as ::rorm::model::Model>::FIELDS
+                .explicit_nullable
+                .select(&mut *ctx),
+            explicit_not_null: <NullableModel as ::rorm::model::Model>::FIELDS
+                .explicit_not_null
+                .select(&mut *ctx),
+        }
+    }
+}
+impl ::std::default::Default for __NullableModel_ValueSpaceImpl {
+    fn default() -> Self {
+        Self::NullableModel
+    }
+}
+impl ::rorm::crud::decoder::Decoder for __NullableModel_Decoder {
+    type Result = NullableModel;
+    fn by_name<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(NullableModel {
+            id: self.id.by_name(row)?,
+            implicit_not_null: self.implicit_not_null.by_name(row)?,
+            implicit_nullable: self.implicit_nullable.by_name(row)?,
+            explicit_nullable: self.explicit_nullable.by_name(row)?,
+            explicit_not_null: self.explicit_not_null.by_name(row)?,
+        })
+    }
+    fn by_index<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(NullableModel {
+            id: self.id.by_index(row)?,
+            implicit_not_null: self.implicit_not_null.by_index(row)?,
+            implicit_nullable: self.implicit_nullable.by_index(row)?,
+            explicit_nullable: self.explicit_nullable.by_index(row)?,
+            explicit_not_null: self.explicit_not_null.by_index(row)?,
+        })
+    }
+}
+impl ::rorm::model::Patch for NullableModel {
+    type Model = NullableModel;
+    type ValueSpaceImpl = __NullableModel_ValueSpaceImpl;
+    type Decoder = __NullableModel_Decoder;
+    fn push_columns(columns: &mut Vec<&'static str>) {
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .id
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .implicit_not_null
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .implicit_nullable
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .explicit_nullable
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .explicit_not_null
+                }),
+            );
+    }
+    fn push_references<'a>(&'a self, values: &mut Vec<::rorm::conditions::Value<'a>>) {
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.id));
+        values
+            .extend(
+                ::rorm::fields::traits::FieldType::as_values(&self.implicit_not_null),
+            );
+        values
+            .extend(
+                ::rorm::fields::traits::FieldType::as_values(&self.implicit_nullable),
+            );
+        values
+            .extend(
+                ::rorm::fields::traits::FieldType::as_values(&self.explicit_nullable),
+            );
+        values
+            .extend(
+                ::rorm::fields::traits::FieldType::as_values(&self.explicit_not_null),
+            );
+    }
+    fn push_values(self, values: &mut Vec<::rorm::conditions::Value>) {
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.id));
+        values
+            .extend(
+                ::rorm::fields::traits::FieldType::into_values(self.implicit_not_null),
+            );
+        values
+            .extend(
+                ::rorm::fields::traits::FieldType::into_values(self.implicit_nullable),
+            );
+        values
+            .extend(
+                ::rorm::fields::traits::FieldType::into_values(self.explicit_nullable),
+            );
+        values
+            .extend(
+                ::rorm::fields::traits::FieldType::into_values(self.explicit_not_null),
+            );
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for NullableModel {
+    type Patch = NullableModel;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, NullableModel> {
+        ::rorm::internal::patch::PatchCow::Owned(self)
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for &'a NullableModel {
+    type Patch = NullableModel;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, NullableModel> {
+        ::rorm::internal::patch::PatchCow::Borrowed(self)
+    }
+}
+impl ::rorm::model::CachedImr for NullableModel {
+    fn cached_imr() -> &'static ::rorm::imr::Model {
+        static IMR: ::std::sync::OnceLock<::rorm::imr::Model> = ::std::sync::OnceLock::new();
+        IMR.get_or_init(::rorm::model::build_imr::<Self>)
+    }
+}
+const _: () = {
+    #[::rorm::linkme::distributed_slice(::rorm::MODELS)]
+    #[linkme(crate = ::rorm::linkme)]
+    static __get_imr: fn() -> ::rorm::imr::Model = <NullableModel as ::rorm::model::Model>::get_imr;
+    let mut count_auto_increment = 0;
+    let mut annos_slice = <__NullableModel_id as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__NullableModel_implicit_not_null as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__NullableModel_implicit_nullable as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__NullableModel_explicit_nullable as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__NullableModel_explicit_not_null as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    assert!(
+        count_auto_increment <= 1, "\"auto_increment\" can only be set once per model"
+    );
+};
+impl ::rorm::model::FieldByIndex<{ 0usize }> for NullableModel {
+    type Field = __NullableModel_id;
+}
+impl ::rorm::model::GetField<__NullableModel_id> for NullableModel {
+    fn get_field(self) -> i64 {
+        self.id
+    }
+    fn borrow_field(&self) -> &i64 {
+        &self.id
+    }
+    fn borrow_field_mut(&mut self) -> &mut i64 {
+        &mut self.id
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 1usize }> for NullableModel {
+    type Field = __NullableModel_implicit_not_null;
+}
+impl ::rorm::model::GetField<__NullableModel_implicit_not_null> for NullableModel {
+    fn get_field(self) -> i64 {
+        self.implicit_not_null
+    }
+    fn borrow_field(&self) -> &i64 {
+        &self.implicit_not_null
+    }
+    fn borrow_field_mut(&mut self) -> &mut i64 {
+        &mut self.implicit_not_null
+    }
+}
+impl ::rorm::model::UpdateField<__NullableModel_implicit_not_null> for NullableModel {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut i64) -> T,
+    ) -> T {
+        update(&self.id, &mut self.implicit_not_null)
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 2usize }> for NullableModel {
+    type Field = __NullableModel_implicit_nullable;
+}
+impl ::rorm::model::GetField<__NullableModel_implicit_nullable> for NullableModel {
+    fn get_field(self) -> Option<i64> {
+        self.implicit_nullable
+    }
+    fn borrow_field(&self) -> &Option<i64> {
+        &self.implicit_nullable
+    }
+    fn borrow_field_mut(&mut self) -> &mut Option<i64> {
+        &mut self.implicit_nullable
+    }
+}
+impl ::rorm::model::UpdateField<__NullableModel_implicit_nullable> for NullableModel {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut Option<i64>) -> T,
+    ) -> T {
+        update(&self.id, &mut self.implicit_nullable)
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 3usize }> for NullableModel {
+    type Field = __NullableModel_explicit_nullable;
+}
+impl ::rorm::model::GetField<__NullableModel_explicit_nullable> for NullableModel {
+    fn get_field(self) -> i64 {
+        self.explicit_nullable
+    }
+    fn borrow_field(&self) -> &i64 {
+        &self.explicit_nullable
+    }
+    fn borrow_field_mut(&mut self) -> &mut i64 {
+        &mut self.explicit_nullable
+    }
+}
+impl ::rorm::model::UpdateField<__NullableModel_explicit_nullable> for NullableModel {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut i64) -> T,
+    ) -> T {
+        update(&self.id, &mut self.explicit_nullable)
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 4usize }> for NullableModel {
+    type Field = __NullableModel_explicit_not_null;
+}
+impl ::rorm::model::GetField<__NullableModel_explicit_not_null> for NullableModel {
+    fn get_field(self) -> Option<i64> {
+        self.explicit_not_null
+    }
+    fn borrow_field(&self) -> &Option<i64> {
+        &self.explicit_not_null
+    }
+    fn borrow_field_mut(&mut self) -> &mut Option<i64> {
+        &mut self.explicit_not_null
+    }
+}
+impl ::rorm::model::UpdateField<__NullableModel_explicit_not_null> for NullableModel {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut Option<i64>) -> T,
+    ) -> T {
+        update(&self.id, &mut self.explicit_not_null)
+    }
+}
@@ -0,0 +1,60 @@
+use rorm::fields::types::MaxStr;
+use rorm::Model;
+use rorm::Patch;
+use schemars::schema_for;
+use serde_json::json;
+
+#[derive(Model)]
+#[rorm(schemars)]
+pub struct SchemarsModel {
+    #[rorm(id)]
+    pub id: i64,
+
+    #[rorm(max_length = 255)]
+    pub name: String,
+
+    #[rorm(max_length = 255)]
+    pub description: Option<String>,
+
+    pub tag: MaxStr<16>,
+}
+
+#[derive(Patch)]
+#[rorm(model = "SchemarsModel", schemars)]
+pub struct SchemarsPatch {
+    pub name: String,
+}
+
+fn main() {
+    assert_eq!(
+        serde_json::to_value(schema_for!(SchemarsModel)).unwrap(),
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "SchemarsModel",
+            "type": "object",
+            "required": ["id", "name", "tag"],
+            "properties": {
+                "id": { "type": "integer", "format": "int64" },
+                "name": { "type": "string", "maxLength": 255 },
+                "description": { "type": ["string", "null"], "maxLength": 255 },
+                "tag": { "$ref": "#/definitions/MaxStr_16" },
+            },
+            "definitions": {
+                "MaxStr_16": { "type": "string", "maxLength": 16 },
+            },
+        })
+    );
+
+    assert_eq!(
+        serde_json::to_value(schema_for!(SchemarsPatch)).unwrap(),
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "SchemarsPatch",
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": { "type": "string", "maxLength": 255 },
+            },
+        })
+    );
+}
@@ -0,0 +1,506 @@
+///rorm's representation of [`SchemarsModel`]'s `id` field
+#[allow(non_camel_case_types)]
+pub struct __SchemarsModel_id(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __SchemarsModel_id {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __SchemarsModel_id {}
+impl ::rorm::internal::field::Field for __SchemarsModel_id {
+    type Type = i64;
+    type Model = SchemarsModel;
+    const INDEX: usize = 0usize;
+    const NAME: &'static str = "id";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: Some(::rorm::internal::hmr::annotations::AutoIncrement),
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: Some(::rorm::internal::hmr::annotations::PrimaryKey),
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__SchemarsModel_id>() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`SchemarsModel`]'s `name` field
+#[allow(non_camel_case_types)]
+pub struct __SchemarsModel_name(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __SchemarsModel_name {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __SchemarsModel_name {}
+impl ::rorm::internal::field::Field for __SchemarsModel_name {
+    type Type = String;
+    type Model = SchemarsModel;
+    const INDEX: usize = 1usize;
+    const NAME: &'static str = "name";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: None,
+        index: None,
+        max_length: Some(::rorm::internal::hmr::annotations::MaxLength(255)),
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__SchemarsModel_name>() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`SchemarsModel`]'s `description` field
+#[allow(non_camel_case_types)]
+pub struct __SchemarsModel_description(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __SchemarsModel_description {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __SchemarsModel_description {}
+impl ::rorm::internal::field::Field for __SchemarsModel_description {
+    type Type = Option<String>;
+    type Model = SchemarsModel;
+    const INDEX: usize = 2usize;
+    const NAME: &'static str = "description";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: None,
+        index: None,
+        max_length: Some(::rorm::internal::hmr::annotations::MaxLength(255)),
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__SchemarsModel_description>() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`SchemarsModel`]'s `tag` field
+#[allow(non_camel_case_types)]
+pub struct __SchemarsModel_tag(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __SchemarsModel_tag {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __SchemarsModel_tag {}
+impl ::rorm::internal::field::Field for __SchemarsModel_tag {
+    type Type = MaxStr<16>;
+    type Model = SchemarsModel;
+    const INDEX: usize = 3usize;
+    const NAME: &'static str = "tag";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__SchemarsModel_tag>() {
+        panic!("{}", err.as_str());
+    }
+};
+///[`SchemarsModel`]'s [`Fields`](::rorm::model::Model::Fields) struct.
+#[allow(non_camel_case_types)]
+pub struct __SchemarsModel_Fields_Struct<Path: ::rorm::internal::relation_path::Path> {
+    ///[`SchemarsModel`]'s `id` field
+    pub id: ::rorm::fields::proxy::FieldProxy<(__SchemarsModel_id, Path)>,
+    ///[`SchemarsModel`]'s `name` field
+    pub name: ::rorm::fields::proxy::FieldProxy<(__SchemarsModel_name, Path)>,
+    ///[`SchemarsModel`]'s `description` field
+    pub description: ::rorm::fields::proxy::FieldProxy<
+        (__SchemarsModel_description, Path),
+    >,
+    ///[`SchemarsModel`]'s `tag` field
+    pub tag: ::rorm::fields::proxy::FieldProxy<(__SchemarsModel_tag, Path)>,
+}
+impl<Path: ::rorm::internal::relation_path::Path> ::rorm::model::ConstNew
+for __SchemarsModel_Fields_Struct<Path> {
+    const NEW: Self = Self {
+        id: ::rorm::fields::proxy::new(),
+        name: ::rorm::fields::proxy::new(),
+        description: ::rorm::fields::proxy::new(),
+        tag: ::rorm::fields::proxy::new(),
+    };
+    const REF: &'static Self = &Self::NEW;
+}
+impl ::std::ops::Deref for __SchemarsModel_ValueSpaceImpl {
+    type Target = <SchemarsModel as ::rorm::Model>::Fields<SchemarsModel>;
+    fn deref(&self) -> &Self::Target {
+        ::rorm::model::ConstNew::REF
+    }
+}
+impl ::rorm::model::Model for SchemarsModel {
+    type Primary = __SchemarsModel_id;
+    type Fields<P: ::rorm::internal::relation_path::Path> = __SchemarsModel_Fields_Struct<
+        P,
+    >;
+    const F: __SchemarsModel_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const FIELDS: __SchemarsModel_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const TABLE: &'static str = "schemarsmodel";
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn push_fields_imr(fields: &mut Vec<::rorm::imr::Field>) {
+        ::rorm::internal::field::push_imr::<__SchemarsModel_id>(&mut *fields);
+        ::rorm::internal::field::push_imr::<__SchemarsModel_name>(&mut *fields);
+        ::rorm::internal::field::push_imr::<__SchemarsModel_description>(&mut *fields);
+        ::rorm::internal::field::push_imr::<__SchemarsModel_tag>(&mut *fields);
+    }
+    fn get_imr() -> ::rorm::imr::Model {
+        <Self as ::rorm::model::CachedImr>::cached_imr().clone()
+    }
+}
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub enum __SchemarsModel_ValueSpaceImpl {
+    SchemarsModel,
+    #[allow(dead_code)]
+    #[doc(hidden)]
+    __SchemarsModel_ValueSpaceImplMarker(::std::marker::PhantomData<SchemarsModel>),
+}
+pub use __SchemarsModel_ValueSpaceImpl::*;
+pub struct __SchemarsModel_Decoder {
+    id: <i64 as ::rorm::fields::traits::FieldType>::Decoder,
+    name: <String as ::rorm::fields::traits::FieldType>::Decoder,
+    description: <Option<String> as ::rorm::fields::traits::FieldType>::Decoder,
+    tag: <MaxStr<16> as ::rorm::fields::traits::FieldType>::Decoder,
+}
+impl ::rorm::crud::selector::Selector for __SchemarsModel_ValueSpaceImpl {
+    type Result = SchemarsModel;
+    type Model = SchemarsModel;
+    type Decoder = __SchemarsModel_Decoder;
+    const INSERT_COMPATIBLE: bool = true;
+    fn select(
+        self,
+        ctx: &mut ::rorm::internal::query_context::QueryContext,
+    ) -> Self::Decoder {
+        __SchemarsModel_Decoder {
+            id: <SchemarsModel as ::rorm::model::Model>::FIELDS.id.select(&mut *ctx),
+            name: <SchemarsModel as ::rorm::model::Model>::FIELDS.name.select(&mut *ctx),
+            description: <SchemarsModel as ::rorm::model::Model>::FIELDS
+                .description
+                .select(&mut *ctx),
+            tag: <SchemarsModel as ::rorm::model::Model>::FIELDS.tag.select(&mut *ctx),
+        }
+    }
+}
+impl ::std::default::Default for __SchemarsModel_ValueSpaceImpl {
+    fn default() -> Self {
+        Self::SchemarsModel
+    }
+}
+impl ::rorm::crud::decoder::Decoder for __SchemarsModel_Decoder {
+    type Result = SchemarsModel;
+    fn by_name<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(SchemarsModel {
+            id: self.id.by_name(row)?,
+            name: self.name.by_name(row)?,
+            description: self.description.by_name(row)?,
+            tag: self.tag.by_name(row)?,
+        })
+    }
+    fn by_index<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(SchemarsModel {
+            id: self.id.by_index(row)?,
+            name: self.name.by_index(row)?,
+            description: self.description.by_index(row)?,
+            tag: self.tag.by_index(row)?,
+        })
+    }
+}
+impl ::rorm::model::Patch for SchemarsModel {
+    type Model = SchemarsModel;
+    type ValueSpaceImpl = __SchemarsModel_ValueSpaceImpl;
+    type Decoder = __SchemarsModel_Decoder;
+    fn push_columns(columns: &mut Vec<&'static str>) {
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .id
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .name
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .description
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .tag
+                }),
+            );
+    }
+    fn push_references<'a>(&'a self, values: &mut Vec<::rorm::conditions::Value<'a>>) {
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.id));
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.name));
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.description));
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.tag));
+    }
+    fn push_values(self, values: &mut Vec<::rorm::conditions::Value>) {
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.id));
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.name));
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.description));
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.tag));
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for SchemarsModel {
+    type Patch = SchemarsModel;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, SchemarsModel> {
+        ::rorm::internal::patch::PatchCow::Owned(self)
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for &'a SchemarsModel {
+    type Patch = SchemarsModel;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, SchemarsModel> {
+        ::rorm::internal::patch::PatchCow::Borrowed(self)
+    }
+}
+impl ::rorm::internal::json_schema::JsonSchema for SchemarsModel {
+    fn schema_name() -> String {
+        "SchemarsModel".to_string()
+    }
+    fn json_schema(
+        gen: &mut ::rorm::internal::json_schema::SchemaGenerator,
+    ) -> ::rorm::internal::json_schema::Schema {
+        let mut object = ::rorm::internal::json_schema::new_object();
+        ::rorm::internal::json_schema::add_field::<
+            __SchemarsModel_id,
+        >(&mut *gen, &mut object, "id");
+        ::rorm::internal::json_schema::add_field::<
+            __SchemarsModel_name,
+        >(&mut *gen, &mut object, "name");
+        ::rorm::internal::json_schema::add_field::<
+            __SchemarsModel_description,
+        >(&mut *gen, &mut object, "description");
+        ::rorm::internal::json_schema::add_field::<
+            __SchemarsModel_tag,
+        >(&mut *gen, &mut object, "tag");
+        ::rorm::internal::json_schema::Schema::Object(object)
+    }
+}
+impl ::rorm::model::CachedImr for SchemarsModel {
+    fn cached_imr() -> &'static ::rorm::imr::Model {
+        static IMR: ::std::sync::OnceLock<::rorm::imr::Model> = ::std::sync::OnceLock::new();
+        IMR.get_or_init(::rorm::model::build_imr::<Self>)
+    }
+}
+const _: () = {
+    #[::rorm::linkme::distributed_slice(::rorm::MODELS)]
+    #[linkme(crate = ::rorm::linkme)]
+    static __get_imr: fn() -> ::rorm::imr::Model = <SchemarsModel as ::rorm::model::Model>::get_imr;
+    let mut count_auto_increment = 0;
+    let mut annos_slice = <__SchemarsModel_id as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__SchemarsModel_name as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__SchemarsModel_description as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__SchemarsModel_tag as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    assert!(
+        count_auto_increment <= 1, "\"auto_increment\" can only be set once per model"
+    );
+};
+impl ::rorm::model::FieldByIndex<{ 0usize }> for SchemarsModel {
+    type Field = __SchemarsModel_id;
+}
+impl ::rorm::model::GetField<__SchemarsModel_id> for SchemarsModel {
+    fn get_field(self) -> i64 {
+        self.id
+    }
+    fn borrow_field(&self) -> &i64 {
+        &self.id
+    }
+    fn borrow_field_mut(&mut self) -> &mut i64 {
+        &mut self.id
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 1usize }> for SchemarsModel {
+    type Field = __SchemarsModel_name;
+}
+impl ::rorm::model::GetField<__SchemarsModel_name> for SchemarsModel {
+    fn get_field(self) -> String {
+        self.name
+    }
+    fn borrow_field(&self) -> &String {
+        &self.name
+    }
+    fn borrow_field_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+impl ::rorm::model::UpdateField<__SchemarsModel_name> for SchemarsModel {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut String) -> T,
+    ) -> T {
+        update(&self.id, &mut self.name)
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 2usize }> for SchemarsModel {
+    type Field = __SchemarsModel_description;
+}
+impl ::rorm::model::GetField<__SchemarsModel_description> for SchemarsModel {
+    fn get_field(self) -> Option<String> {
+        self.description
+    }
+    fn borrow_field(&self) -> &Option<String> {
+        &self.description
+    }
+    fn borrow_field_mut(&mut self) -> &mut Option<String> {
+        &mut self.description
+    }
+}
+impl ::rorm::model::UpdateField<__SchemarsModel_description> for SchemarsModel {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut Option<String>) -> T,
+    ) -> T {
+        update(&self.id, &mut self.description)
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 3usize }> for SchemarsModel {
+    type Field = __SchemarsModel_tag;
+}
+impl ::rorm::model::GetField<__SchemarsModel_tag> for SchemarsModel {
+    fn get_field(self) -> MaxStr<16> {
+        self.tag
+    }
+    fn borrow_field(&self) -> &MaxStr<16> {
+        &self.tag
+    }
+    fn borrow_field_mut(&mut self) -> &mut MaxStr<16> {
+        &mut self.tag
+    }
+}
+impl ::rorm::model::UpdateField<__SchemarsModel_tag> for SchemarsModel {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut MaxStr<16>) -> T,
+    ) -> T {
+        update(&self.id, &mut self.tag)
+    }
+}
@@ -0,0 +1,107 @@
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub enum __SchemarsPatch_ValueSpaceImpl {
+    SchemarsPatch,
+    #[allow(dead_code)]
+    #[doc(hidden)]
+    __SchemarsPatch_ValueSpaceImplMarker(::std::marker::PhantomData<SchemarsPatch>),
+}
+pub use __SchemarsPatch_ValueSpaceImpl::*;
+pub struct __SchemarsPatch_Decoder {
+    name: <String as ::rorm::fields::traits::FieldType>::Decoder,
+}
+impl ::rorm::crud::selector::Selector for __SchemarsPatch_ValueSpaceImpl {
+    type Result = SchemarsPatch;
+    type Model = SchemarsModel;
+    type Decoder = __SchemarsPatch_Decoder;
+    const INSERT_COMPATIBLE: bool = true;
+    fn select(
+        self,
+        ctx: &mut ::rorm::internal::query_context::QueryContext,
+    ) -> Self::Decoder {
+        __SchemarsPatch_Decoder {
+            name: <SchemarsModel as ::rorm::model::Model>::FIELDS.name.select(&mut *ctx),
+        }
+    }
+}
+impl ::std::default::Default for __SchemarsPatch_ValueSpaceImpl {
+    fn default() -> Self {
+        Self::SchemarsPatch
+    }
+}
+impl ::rorm::crud::decoder::Decoder for __SchemarsPatch_Decoder {
+    type Result = SchemarsPatch;
+    fn by_name<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(SchemarsPatch {
+            name: self.name.by_name(row)?,
+        })
+    }
+    fn by_index<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(SchemarsPatch {
+            name: self.name.by_index(row)?,
+        })
+    }
+}
+impl ::rorm::model::Patch for SchemarsPatch {
+    type Model = SchemarsModel;
+    type ValueSpaceImpl = __SchemarsPatch_ValueSpaceImpl;
+    type Decoder = __SchemarsPatch_Decoder;
+    fn push_columns(columns: &mut Vec<&'static str>) {
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .name
+                }),
+            );
+    }
+    fn push_references<'a>(&'a self, values: &mut Vec<::rorm::conditions::Value<'a>>) {
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.name));
+    }
+    fn push_values(self, values: &mut Vec<::rorm::conditions::Value>) {
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.name));
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for SchemarsPatch {
+    type Patch = SchemarsPatch;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, SchemarsPatch> {
+        ::rorm::internal::patch::PatchCow::Owned(self)
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for &'a SchemarsPatch {
+    type Patch = SchemarsPatch;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, SchemarsPatch> {
+        ::rorm::internal::patch::PatchCow::Borrowed(self)
+    }
+}
+impl ::rorm::internal::json_schema::JsonSchema for SchemarsPatch {
+    fn schema_name() -> String {
+        "SchemarsPatch".to_string()
+    }
+    fn json_schema(
+        gen: &mut ::rorm::internal::json_schema::SchemaGenerator,
+    ) -> ::rorm::internal::json_schema::Schema {
+        let mut object = ::rorm::internal::json_schema::new_object();
+        ::rorm::internal::json_schema::add_field::<
+            ::rorm::get_field!(SchemarsPatch, name),
+        >(&mut *gen, &mut object, "name");
+        ::rorm::internal::json_schema::Schema::Object(object)
+    }
+}
+impl ::rorm::model::GetField<::rorm::get_field!(SchemarsPatch, name)> for SchemarsPatch {
+    fn get_field(self) -> String {
+        self.name
+    }
+    fn borrow_field(&self) -> &String {
+        &self.name
+    }
+    fn borrow_field_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
@@ -0,0 +1,32 @@
+use rorm::Model;
+use rorm::Patch;
+
+#[derive(Model)]
+#[rorm(serde(columns))]
+pub struct SerdeModel {
+    #[rorm(id)]
+    pub id: i64,
+
+    #[rorm(max_length = 255, rename = "display_name")]
+    pub name: String,
+}
+
+#[derive(Patch)]
+#[rorm(model = "SerdeModel", serde)]
+pub struct SerdePatch {
+    pub name: String,
+}
+
+fn main() {
+    let patch: SerdePatch = serde_json::from_str(r#"{"name":"alice"}"#).unwrap();
+    assert_eq!(patch.name, "alice");
+
+    let model = SerdeModel {
+        id: 1,
+        name: patch.name,
+    };
+    assert_eq!(
+        serde_json::to_string(&model).unwrap(),
+        r#"{"id":1,"display_name":"alice"}"#
+    );
+}
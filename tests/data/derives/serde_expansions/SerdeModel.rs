@@ -0,0 +1,338 @@
+///rorm's representation of [`SerdeModel`]'s `id` field
+#[allow(non_camel_case_types)]
+pub struct __SerdeModel_id(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __SerdeModel_id {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __SerdeModel_id {}
+impl ::rorm::internal::field::Field for __SerdeModel_id {
+    type Type = i64;
+    type Model = SerdeModel;
+    const INDEX: usize = 0usize;
+    const NAME: &'static str = "id";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: Some(::rorm::internal::hmr::annotations::AutoIncrement),
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: Some(::rorm::internal::hmr::annotations::PrimaryKey),
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__SerdeModel_id>() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`SerdeModel`]'s `name` field
+#[allow(non_camel_case_types)]
+pub struct __SerdeModel_name(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __SerdeModel_name {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __SerdeModel_name {}
+impl ::rorm::internal::field::Field for __SerdeModel_name {
+    type Type = String;
+    type Model = SerdeModel;
+    const INDEX: usize = 1usize;
+    const NAME: &'static str = "display_name";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: None,
+        index: None,
+        max_length: Some(::rorm::internal::hmr::annotations::MaxLength(255)),
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__SerdeModel_name>() {
+        panic!("{}", err.as_str());
+    }
+};
+///[`SerdeModel`]'s [`Fields`](::rorm::model::Model::Fields) struct.
+#[allow(non_camel_case_types)]
+pub struct __SerdeModel_Fields_Struct<Path: ::rorm::internal::relation_path::Path> {
+    ///[`SerdeModel`]'s `id` field
+    pub id: ::rorm::fields::proxy::FieldProxy<(__SerdeModel_id, Path)>,
+    ///[`SerdeModel`]'s `name` field
+    pub name: ::rorm::fields::proxy::FieldProxy<(__SerdeModel_name, Path)>,
+}
+impl<Path: ::rorm::internal::relation_path::Path> ::rorm::model::ConstNew
+for __SerdeModel_Fields_Struct<Path> {
+    const NEW: Self = Self {
+        id: ::rorm::fields::proxy::new(),
+        name: ::rorm::fields::proxy::new(),
+    };
+    const REF: &'static Self = &Self::NEW;
+}
+impl ::std::ops::Deref for __SerdeModel_ValueSpaceImpl {
+    type Target = <SerdeModel as ::rorm::Model>::Fields<SerdeModel>;
+    fn deref(&self) -> &Self::Target {
+        ::rorm::model::ConstNew::REF
+    }
+}
+impl ::rorm::model::Model for SerdeModel {
+    type Primary = __SerdeModel_id;
+    type Fields<P: ::rorm::internal::relation_path::Path> = __SerdeModel_Fields_Struct<
+        P,
+    >;
+    const F: __SerdeModel_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const FIELDS: __SerdeModel_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const TABLE: &'static str = "serdemodel";
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn push_fields_imr(fields: &mut Vec<::rorm::imr::Field>) {
+        ::rorm::internal::field::push_imr::<__SerdeModel_id>(&mut *fields);
+        ::rorm::internal::field::push_imr::<__SerdeModel_name>(&mut *fields);
+    }
+    fn get_imr() -> ::rorm::imr::Model {
+        <Self as ::rorm::model::CachedImr>::cached_imr().clone()
+    }
+}
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub enum __SerdeModel_ValueSpaceImpl {
+    SerdeModel,
+    #[allow(dead_code)]
+    #[doc(hidden)]
+    __SerdeModel_ValueSpaceImplMarker(::std::marker::PhantomData<SerdeModel>),
+}
+pub use __SerdeModel_ValueSpaceImpl::*;
+pub struct __SerdeModel_Decoder {
+    id: <i64 as ::rorm::fields::traits::FieldType>::Decoder,
+    name: <String as ::rorm::fields::traits::FieldType>::Decoder,
+}
+impl ::rorm::crud::selector::Selector for __SerdeModel_ValueSpaceImpl {
+    type Result = SerdeModel;
+    type Model = SerdeModel;
+    type Decoder = __SerdeModel_Decoder;
+    const INSERT_COMPATIBLE: bool = true;
+    fn select(
+        self,
+        ctx: &mut ::rorm::internal::query_context::QueryContext,
+    ) -> Self::Decoder {
+        __SerdeModel_Decoder {
+            id: <SerdeModel as ::rorm::model::Model>::FIELDS.id.select(&mut *ctx),
+            name: <SerdeModel as ::rorm::model::Model>::FIELDS.name.select(&mut *ctx),
+        }
+    }
+}
+impl ::std::default::Default for __SerdeModel_ValueSpaceImpl {
+    fn default() -> Self {
+        Self::SerdeModel
+    }
+}
+impl ::rorm::crud::decoder::Decoder for __SerdeModel_Decoder {
+    type Result = SerdeModel;
+    fn by_name<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(SerdeModel {
+            id: self.id.by_name(row)?,
+            name: self.name.by_name(row)?,
+        })
+    }
+    fn by_index<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(SerdeModel {
+            id: self.id.by_index(row)?,
+            name: self.name.by_index(row)?,
+        })
+    }
+}
+impl ::rorm::model::Patch for SerdeModel {
+    type Model = SerdeModel;
+    type ValueSpaceImpl = __SerdeModel_ValueSpaceImpl;
+    type Decoder = __SerdeModel_Decoder;
+    fn push_columns(columns: &mut Vec<&'static str>) {
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .id
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .name
+                }),
+            );
+    }
+    fn push_references<'a>(&'a self, values: &mut Vec<::rorm::conditions::Value<'a>>) {
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.id));
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.name));
+    }
+    fn push_values(self, values: &mut Vec<::rorm::conditions::Value>) {
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.id));
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.name));
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for SerdeModel {
+    type Patch = SerdeModel;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, SerdeModel> {
+        ::rorm::internal::patch::PatchCow::Owned(self)
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for &'a SerdeModel {
+    type Patch = SerdeModel;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, SerdeModel> {
+        ::rorm::internal::patch::PatchCow::Borrowed(self)
+    }
+}
+const _: () = {
+    #[derive(::rorm::serde::Serialize)]
+    #[serde(crate = "::rorm::serde", rename = "SerdeModel")]
+    struct __Serialize<'a> {
+        #[serde(rename = "id")]
+        id: &'a i64,
+        #[serde(rename = "display_name")]
+        name: &'a String,
+    }
+    impl ::rorm::serde::Serialize for SerdeModel {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ::rorm::serde::Serializer,
+        {
+            ::rorm::serde::Serialize::serialize(
+                &__Serialize {
+                    id: &self.id,
+                    name: &self.name,
+                },
+                serializer,
+            )
+        }
+    }
+    #[derive(::rorm::serde::Deserialize)]
+    #[serde(crate = "::rorm::serde", rename = "SerdeModel")]
+    struct __Deserialize {
+        #[serde(rename = "id")]
+        id: i64,
+        #[serde(rename = "display_name")]
+        name: String,
+    }
+    impl<'de> ::rorm::serde::Deserialize<'de> for SerdeModel {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: ::rorm::serde::Deserializer<'de>,
+        {
+            let __Deserialize { id, name } = ::rorm::serde::Deserialize::deserialize(
+                deserializer,
+            )?;
+            Ok(Self { id, name })
+        }
+    }
+};
+impl ::rorm::model::CachedImr for SerdeModel {
+    fn cached_imr() -> &'static ::rorm::imr::Model {
+        static IMR: ::std::sync::OnceLock<::rorm::imr::Model> = ::std::sync::OnceLock::new();
+        IMR.get_or_init(::rorm::model::build_imr::<Self>)
+    }
+}
+const _: () = {
+    #[::rorm::linkme::distributed_slice(::rorm::MODELS)]
+    #[linkme(crate = ::rorm::linkme)]
+    static __get_imr: fn() -> ::rorm::imr::Model = <SerdeModel as ::rorm::model::Model>::get_imr;
+    let mut count_auto_increment = 0;
+    let mut annos_slice = <__SerdeModel_id as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__SerdeModel_name as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    assert!(
+        count_auto_increment <= 1, "\"auto_increment\" can only be set once per model"
+    );
+};
+impl ::rorm::model::FieldByIndex<{ 0usize }> for SerdeModel {
+    type Field = __SerdeModel_id;
+}
+impl ::rorm::model::GetField<__SerdeModel_id> for SerdeModel {
+    fn get_field(self) -> i64 {
+        self.id
+    }
+    fn borrow_field(&self) -> &i64 {
+        &self.id
+    }
+    fn borrow_field_mut(&mut self) -> &mut i64 {
+        &mut self.id
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 1usize }> for SerdeModel {
+    type Field = __SerdeModel_name;
+}
+impl ::rorm::model::GetField<__SerdeModel_name> for SerdeModel {
+    fn get_field(self) -> String {
+        self.name
+    }
+    fn borrow_field(&self) -> &String {
+        &self.name
+    }
+    fn borrow_field_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+impl ::rorm::model::UpdateField<__SerdeModel_name> for SerdeModel {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut String) -> T,
+    ) -> T {
+        update(&self.id, &mut self.name)
+    }
+}
@@ -0,0 +1,127 @@
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub enum __SerdePatch_ValueSpaceImpl {
+    SerdePatch,
+    #[allow(dead_code)]
+    #[doc(hidden)]
+    __SerdePatch_ValueSpaceImplMarker(::std::marker::PhantomData<SerdePatch>),
+}
+pub use __SerdePatch_ValueSpaceImpl::*;
+pub struct __SerdePatch_Decoder {
+    name: <String as ::rorm::fields::traits::FieldType>::Decoder,
+}
+impl ::rorm::crud::selector::Selector for __SerdePatch_ValueSpaceImpl {
+    type Result = SerdePatch;
+    type Model = SerdeModel;
+    type Decoder = __SerdePatch_Decoder;
+    const INSERT_COMPATIBLE: bool = true;
+    fn select(
+        self,
+        ctx: &mut ::rorm::internal::query_context::QueryContext,
+    ) -> Self::Decoder {
+        __SerdePatch_Decoder {
+            name: <SerdeModel as ::rorm::model::Model>::FIELDS.name.select(&mut *ctx),
+        }
+    }
+}
+impl ::std::default::Default for __SerdePatch_ValueSpaceImpl {
+    fn default() -> Self {
+        Self::SerdePatch
+    }
+}
+impl ::rorm::crud::decoder::Decoder for __SerdePatch_Decoder {
+    type Result = SerdePatch;
+    fn by_name<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(SerdePatch {
+            name: self.name.by_name(row)?,
+        })
+    }
+    fn by_index<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(SerdePatch {
+            name: self.name.by_index(row)?,
+        })
+    }
+}
+impl ::rorm::model::Patch for SerdePatch {
+    type Model = SerdeModel;
+    type ValueSpaceImpl = __SerdePatch_ValueSpaceImpl;
+    type Decoder = __SerdePatch_Decoder;
+    fn push_columns(columns: &mut Vec<&'static str>) {
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .name
+                }),
+            );
+    }
+    fn push_references<'a>(&'a self, values: &mut Vec<::rorm::conditions::Value<'a>>) {
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.name));
+    }
+    fn push_values(self, values: &mut Vec<::rorm::conditions::Value>) {
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.name));
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for SerdePatch {
+    type Patch = SerdePatch;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, SerdePatch> {
+        ::rorm::internal::patch::PatchCow::Owned(self)
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for &'a SerdePatch {
+    type Patch = SerdePatch;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, SerdePatch> {
+        ::rorm::internal::patch::PatchCow::Borrowed(self)
+    }
+}
+const _: () = {
+    #[derive(::rorm::serde::Serialize)]
+    #[serde(crate = "::rorm::serde", rename = "SerdePatch")]
+    struct __Serialize<'a> {
+        name: &'a String,
+    }
+    impl ::rorm::serde::Serialize for SerdePatch {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ::rorm::serde::Serializer,
+        {
+            ::rorm::serde::Serialize::serialize(
+                &__Serialize { name: &self.name },
+                serializer,
+            )
+        }
+    }
+    #[derive(::rorm::serde::Deserialize)]
+    #[serde(crate = "::rorm::serde", rename = "SerdePatch")]
+    struct __Deserialize {
+        name: String,
+    }
+    impl<'de> ::rorm::serde::Deserialize<'de> for SerdePatch {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: ::rorm::serde::Deserializer<'de>,
+        {
+            let __Deserialize { name } = ::rorm::serde::Deserialize::deserialize(
+                deserializer,
+            )?;
+            Ok(Self { name })
+        }
+    }
+};
+impl ::rorm::model::GetField<::rorm::get_field!(SerdePatch, name)> for SerdePatch {
+    fn get_field(self) -> String {
+        self.name
+    }
+    fn borrow_field(&self) -> &String {
+        &self.name
+    }
+    fn borrow_field_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
@@ -0,0 +1,52 @@
+use rorm::fields::types::MaxStr;
+use rorm::Model;
+use rorm::Patch;
+use serde_json::json;
+use utoipa::ToSchema;
+
+#[derive(Model)]
+#[rorm(utoipa)]
+pub struct UtoipaModel {
+    #[rorm(id)]
+    pub id: i64,
+
+    pub name: MaxStr<255>,
+
+    pub age: Option<i32>,
+}
+
+#[derive(Patch)]
+#[rorm(model = "UtoipaModel", utoipa)]
+pub struct UtoipaPatch {
+    pub name: MaxStr<255>,
+}
+
+fn main() {
+    let (name, schema) = UtoipaModel::schema();
+    assert_eq!(name, "UtoipaModel");
+    assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        json!({
+            "type": "object",
+            "required": ["id", "name"],
+            "properties": {
+                "id": { "type": "integer", "format": "int64" },
+                "name": { "type": "string", "maxLength": 255 },
+                "age": { "type": "integer", "format": "int32", "nullable": true },
+            },
+        })
+    );
+
+    let (name, schema) = UtoipaPatch::schema();
+    assert_eq!(name, "UtoipaPatch");
+    assert_eq!(
+        serde_json::to_value(schema).unwrap(),
+        json!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": { "type": "string", "maxLength": 255 },
+            },
+        })
+    );
+}
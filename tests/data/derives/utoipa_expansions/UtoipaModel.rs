@@ -0,0 +1,405 @@
+///rorm's representation of [`UtoipaModel`]'s `id` field
+#[allow(non_camel_case_types)]
+pub struct __UtoipaModel_id(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __UtoipaModel_id {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __UtoipaModel_id {}
+impl ::rorm::internal::field::Field for __UtoipaModel_id {
+    type Type = i64;
+    type Model = UtoipaModel;
+    const INDEX: usize = 0usize;
+    const NAME: &'static str = "id";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: Some(::rorm::internal::hmr::annotations::AutoIncrement),
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: Some(::rorm::internal::hmr::annotations::PrimaryKey),
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__UtoipaModel_id>() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`UtoipaModel`]'s `name` field
+#[allow(non_camel_case_types)]
+pub struct __UtoipaModel_name(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __UtoipaModel_name {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __UtoipaModel_name {}
+impl ::rorm::internal::field::Field for __UtoipaModel_name {
+    type Type = MaxStr<255>;
+    type Model = UtoipaModel;
+    const INDEX: usize = 1usize;
+    const NAME: &'static str = "name";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__UtoipaModel_name>() {
+        panic!("{}", err.as_str());
+    }
+};
+///rorm's representation of [`UtoipaModel`]'s `age` field
+#[allow(non_camel_case_types)]
+pub struct __UtoipaModel_age(::std::marker::PhantomData<()>);
+impl ::std::clone::Clone for __UtoipaModel_age {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl ::std::marker::Copy for __UtoipaModel_age {}
+impl ::rorm::internal::field::Field for __UtoipaModel_age {
+    type Type = Option<i32>;
+    type Model = UtoipaModel;
+    const INDEX: usize = 2usize;
+    const NAME: &'static str = "age";
+    const EXPLICIT_ANNOTATIONS: ::rorm::internal::hmr::annotations::Annotations = ::rorm::internal::hmr::annotations::Annotations {
+        auto_create_time: None,
+        auto_update_time: None,
+        auto_increment: None,
+        choices: None,
+        default: None,
+        index: None,
+        max_length: None,
+        no_constraint: None,
+        on_delete: None,
+        on_update: None,
+        primary_key: None,
+        unique: None,
+        explicit_nullable: None,
+        nullable: false,
+        foreign: None,
+    };
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn new() -> Self {
+        Self(::std::marker::PhantomData)
+    }
+}
+const _: () = {
+    if let Err(err) = ::rorm::internal::field::check::<__UtoipaModel_age>() {
+        panic!("{}", err.as_str());
+    }
+};
+///[`UtoipaModel`]'s [`Fields`](::rorm::model::Model::Fields) struct.
+#[allow(non_camel_case_types)]
+pub struct __UtoipaModel_Fields_Struct<Path: ::rorm::internal::relation_path::Path> {
+    ///[`UtoipaModel`]'s `id` field
+    pub id: ::rorm::fields::proxy::FieldProxy<(__UtoipaModel_id, Path)>,
+    ///[`UtoipaModel`]'s `name` field
+    pub name: ::rorm::fields::proxy::FieldProxy<(__UtoipaModel_name, Path)>,
+    ///[`UtoipaModel`]'s `age` field
+    pub age: ::rorm::fields::proxy::FieldProxy<(__UtoipaModel_age, Path)>,
+}
+impl<Path: ::rorm::internal::relation_path::Path> ::rorm::model::ConstNew
+for __UtoipaModel_Fields_Struct<Path> {
+    const NEW: Self = Self {
+        id: ::rorm::fields::proxy::new(),
+        name: ::rorm::fields::proxy::new(),
+        age: ::rorm::fields::proxy::new(),
+    };
+    const REF: &'static Self = &Self::NEW;
+}
+impl ::std::ops::Deref for __UtoipaModel_ValueSpaceImpl {
+    type Target = <UtoipaModel as ::rorm::Model>::Fields<UtoipaModel>;
+    fn deref(&self) -> &Self::Target {
+        ::rorm::model::ConstNew::REF
+    }
+}
+impl ::rorm::model::Model for UtoipaModel {
+    type Primary = __UtoipaModel_id;
+    type Fields<P: ::rorm::internal::relation_path::Path> = __UtoipaModel_Fields_Struct<
+        P,
+    >;
+    const F: __UtoipaModel_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const FIELDS: __UtoipaModel_Fields_Struct<Self> = ::rorm::model::ConstNew::NEW;
+    const TABLE: &'static str = "utoipamodel";
+    const SOURCE: ::rorm::internal::hmr::Source = ::rorm::internal::hmr::Source {
+        file: ::std::file!(),
+        line: ::std::line!() as usize,
+        column: ::std::column!() as usize,
+    };
+    fn push_fields_imr(fields: &mut Vec<::rorm::imr::Field>) {
+        ::rorm::internal::field::push_imr::<__UtoipaModel_id>(&mut *fields);
+        ::rorm::internal::field::push_imr::<__UtoipaModel_name>(&mut *fields);
+        ::rorm::internal::field::push_imr::<__UtoipaModel_age>(&mut *fields);
+    }
+    fn get_imr() -> ::rorm::imr::Model {
+        <Self as ::rorm::model::CachedImr>::cached_imr().clone()
+    }
+}
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub enum __UtoipaModel_ValueSpaceImpl {
+    UtoipaModel,
+    #[allow(dead_code)]
+    #[doc(hidden)]
+    __UtoipaModel_ValueSpaceImplMarker(::std::marker::PhantomData<UtoipaModel>),
+}
+pub use __UtoipaModel_ValueSpaceImpl::*;
+pub struct __UtoipaModel_Decoder {
+    id: <i64 as ::rorm::fields::traits::FieldType>::Decoder,
+    name: <MaxStr<255> as ::rorm::fields::traits::FieldType>::Decoder,
+    age: <Option<i32> as ::rorm::fields::traits::FieldType>::Decoder,
+}
+impl ::rorm::crud::selector::Selector for __UtoipaModel_ValueSpaceImpl {
+    type Result = UtoipaModel;
+    type Model = UtoipaModel;
+    type Decoder = __UtoipaModel_Decoder;
+    const INSERT_COMPATIBLE: bool = true;
+    fn select(
+        self,
+        ctx: &mut ::rorm::internal::query_context::QueryContext,
+    ) -> Self::Decoder {
+        __UtoipaModel_Decoder {
+            id: <UtoipaModel as ::rorm::model::Model>::FIELDS.id.select(&mut *ctx),
+            name: <UtoipaModel as ::rorm::model::Model>::FIELDS.name.select(&mut *ctx),
+            age: <UtoipaModel as ::rorm::model::Model>::FIELDS.age.select(&mut *ctx),
+        }
+    }
+}
+impl ::std::default::Default for __UtoipaModel_ValueSpaceImpl {
+    fn default() -> Self {
+        Self::UtoipaModel
+    }
+}
+impl ::rorm::crud::decoder::Decoder for __UtoipaModel_Decoder {
+    type Result = UtoipaModel;
+    fn by_name<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(UtoipaModel {
+            id: self.id.by_name(row)?,
+            name: self.name.by_name(row)?,
+            age: self.age.by_name(row)?,
+        })
+    }
+    fn by_index<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(UtoipaModel {
+            id: self.id.by_index(row)?,
+            name: self.name.by_index(row)?,
+            age: self.age.by_index(row)?,
+        })
+    }
+}
+impl ::rorm::model::Patch for UtoipaModel {
+    type Model = UtoipaModel;
+    type ValueSpaceImpl = __UtoipaModel_ValueSpaceImpl;
+    type Decoder = __UtoipaModel_Decoder;
+    fn push_columns(columns: &mut Vec<&'static str>) {
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .id
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .name
+                }),
+            );
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .age
+                }),
+            );
+    }
+    fn push_references<'a>(&'a self, values: &mut Vec<::rorm::conditions::Value<'a>>) {
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.id));
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.name));
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.age));
+    }
+    fn push_values(self, values: &mut Vec<::rorm::conditions::Value>) {
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.id));
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.name));
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.age));
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for UtoipaModel {
+    type Patch = UtoipaModel;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, UtoipaModel> {
+        ::rorm::internal::patch::PatchCow::Owned(self)
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for &'a UtoipaModel {
+    type Patch = UtoipaModel;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, UtoipaModel> {
+        ::rorm::internal::patch::PatchCow::Borrowed(self)
+    }
+}
+impl<'s> ::rorm::internal::to_schema::ToSchema<'s> for UtoipaModel {
+    fn schema() -> (
+        &'s str,
+        ::rorm::internal::to_schema::RefOr<::rorm::internal::to_schema::Schema>,
+    ) {
+        let object = ::rorm::internal::to_schema::new_object();
+        let object = ::rorm::internal::to_schema::add_field::<
+            __UtoipaModel_id,
+        >(object, "id");
+        let object = ::rorm::internal::to_schema::add_field::<
+            __UtoipaModel_name,
+        >(object, "name");
+        let object = ::rorm::internal::to_schema::add_field::<
+            __UtoipaModel_age,
+        >(object, "age");
+        ("UtoipaModel", ::rorm::internal::to_schema::build_object(object))
+    }
+}
+impl ::rorm::model::CachedImr for UtoipaModel {
+    fn cached_imr() -> &'static ::rorm::imr::Model {
+        static IMR: ::std::sync::OnceLock<::rorm::imr::Model> = ::std::sync::OnceLock::new();
+        IMR.get_or_init(::rorm::model::build_imr::<Self>)
+    }
+}
+const _: () = {
+    #[::rorm::linkme::distributed_slice(::rorm::MODELS)]
+    #[linkme(crate = ::rorm::linkme)]
+    static __get_imr: fn() -> ::rorm::imr::Model = <UtoipaModel as ::rorm::model::Model>::get_imr;
+    let mut count_auto_increment = 0;
+    let mut annos_slice = <__UtoipaModel_id as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__UtoipaModel_name as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    let mut annos_slice = <__UtoipaModel_age as ::rorm::internal::field::Field>::EFFECTIVE_ANNOTATIONS
+        .as_slice();
+    while let [annos, tail @ ..] = annos_slice {
+        annos_slice = tail;
+        if annos.auto_increment.is_some() {
+            count_auto_increment += 1;
+        }
+    }
+    assert!(
+        count_auto_increment <= 1, "\"auto_increment\" can only be set once per model"
+    );
+};
+impl ::rorm::model::FieldByIndex<{ 0usize }> for UtoipaModel {
+    type Field = __UtoipaModel_id;
+}
+impl ::rorm::model::GetField<__UtoipaModel_id> for UtoipaModel {
+    fn get_field(self) -> i64 {
+        self.id
+    }
+    fn borrow_field(&self) -> &i64 {
+        &self.id
+    }
+    fn borrow_field_mut(&mut self) -> &mut i64 {
+        &mut self.id
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 1usize }> for UtoipaModel {
+    type Field = __UtoipaModel_name;
+}
+impl ::rorm::model::GetField<__UtoipaModel_name> for UtoipaModel {
+    fn get_field(self) -> MaxStr<255> {
+        self.name
+    }
+    fn borrow_field(&self) -> &MaxStr<255> {
+        &self.name
+    }
+    fn borrow_field_mut(&mut self) -> &mut MaxStr<255> {
+        &mut self.name
+    }
+}
+impl ::rorm::model::UpdateField<__UtoipaModel_name> for UtoipaModel {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut MaxStr<255>) -> T,
+    ) -> T {
+        update(&self.id, &mut self.name)
+    }
+}
+impl ::rorm::model::FieldByIndex<{ 2usize }> for UtoipaModel {
+    type Field = __UtoipaModel_age;
+}
+impl ::rorm::model::GetField<__UtoipaModel_age> for UtoipaModel {
+    fn get_field(self) -> Option<i32> {
+        self.age
+    }
+    fn borrow_field(&self) -> &Option<i32> {
+        &self.age
+    }
+    fn borrow_field_mut(&mut self) -> &mut Option<i32> {
+        &mut self.age
+    }
+}
+impl ::rorm::model::UpdateField<__UtoipaModel_age> for UtoipaModel {
+    fn update_field<'m, T>(
+        &'m mut self,
+        update: impl FnOnce(&'m i64, &'m mut Option<i32>) -> T,
+    ) -> T {
+        update(&self.id, &mut self.age)
+    }
+}
@@ -0,0 +1,105 @@
+#[doc(hidden)]
+#[allow(non_camel_case_types)]
+pub enum __UtoipaPatch_ValueSpaceImpl {
+    UtoipaPatch,
+    #[allow(dead_code)]
+    #[doc(hidden)]
+    __UtoipaPatch_ValueSpaceImplMarker(::std::marker::PhantomData<UtoipaPatch>),
+}
+pub use __UtoipaPatch_ValueSpaceImpl::*;
+pub struct __UtoipaPatch_Decoder {
+    name: <MaxStr<255> as ::rorm::fields::traits::FieldType>::Decoder,
+}
+impl ::rorm::crud::selector::Selector for __UtoipaPatch_ValueSpaceImpl {
+    type Result = UtoipaPatch;
+    type Model = UtoipaModel;
+    type Decoder = __UtoipaPatch_Decoder;
+    const INSERT_COMPATIBLE: bool = true;
+    fn select(
+        self,
+        ctx: &mut ::rorm::internal::query_context::QueryContext,
+    ) -> Self::Decoder {
+        __UtoipaPatch_Decoder {
+            name: <UtoipaModel as ::rorm::model::Model>::FIELDS.name.select(&mut *ctx),
+        }
+    }
+}
+impl ::std::default::Default for __UtoipaPatch_ValueSpaceImpl {
+    fn default() -> Self {
+        Self::UtoipaPatch
+    }
+}
+impl ::rorm::crud::decoder::Decoder for __UtoipaPatch_Decoder {
+    type Result = UtoipaPatch;
+    fn by_name<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(UtoipaPatch {
+            name: self.name.by_name(row)?,
+        })
+    }
+    fn by_index<'index>(
+        &'index self,
+        row: &'_ ::rorm::db::Row,
+    ) -> Result<Self::Result, ::rorm::db::row::RowError<'index>> {
+        Ok(UtoipaPatch {
+            name: self.name.by_index(row)?,
+        })
+    }
+}
+impl ::rorm::model::Patch for UtoipaPatch {
+    type Model = UtoipaModel;
+    type ValueSpaceImpl = __UtoipaPatch_ValueSpaceImpl;
+    type Decoder = __UtoipaPatch_Decoder;
+    fn push_columns(columns: &mut Vec<&'static str>) {
+        columns
+            .extend(
+                ::rorm::fields::proxy::columns(|| {
+                    <<Self as ::rorm::model::Patch>::Model as ::rorm::model::Model>::FIELDS
+                        .name
+                }),
+            );
+    }
+    fn push_references<'a>(&'a self, values: &mut Vec<::rorm::conditions::Value<'a>>) {
+        values.extend(::rorm::fields::traits::FieldType::as_values(&self.name));
+    }
+    fn push_values(self, values: &mut Vec<::rorm::conditions::Value>) {
+        values.extend(::rorm::fields::traits::FieldType::into_values(self.name));
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for UtoipaPatch {
+    type Patch = UtoipaPatch;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, UtoipaPatch> {
+        ::rorm::internal::patch::PatchCow::Owned(self)
+    }
+}
+impl<'a> ::rorm::internal::patch::IntoPatchCow<'a> for &'a UtoipaPatch {
+    type Patch = UtoipaPatch;
+    fn into_patch_cow(self) -> ::rorm::internal::patch::PatchCow<'a, UtoipaPatch> {
+        ::rorm::internal::patch::PatchCow::Borrowed(self)
+    }
+}
+impl<'s> ::rorm::internal::to_schema::ToSchema<'s> for UtoipaPatch {
+    fn schema() -> (
+        &'s str,
+        ::rorm::internal::to_schema::RefOr<::rorm::internal::to_schema::Schema>,
+    ) {
+        let object = ::rorm::internal::to_schema::new_object();
+        let object = ::rorm::internal::to_schema::add_field::<
+            ::rorm::get_field!(UtoipaPatch, name),
+        >(object, "name");
+        ("UtoipaPatch", ::rorm::internal::to_schema::build_object(object))
+    }
+}
+impl ::rorm::model::GetField<::rorm::get_field!(UtoipaPatch, name)> for UtoipaPatch {
+    fn get_field(self) -> MaxStr<255> {
+        self.name
+    }
+    fn borrow_field(&self) -> &MaxStr<255> {
+        &self.name
+    }
+    fn borrow_field_mut(&mut self) -> &mut MaxStr<255> {
+        &mut self.name
+    }
+}
@@ -0,0 +1,10 @@
+//! Compiles models without `max_length` on their string fields while `RORM_DEFAULT_MAX_LENGTH` is set
+
+use std::env;
+
+#[test]
+fn default_max_length() {
+    // Read by `option_env!` while trybuild compiles rorm for the test cases
+    env::set_var("RORM_DEFAULT_MAX_LENGTH", "255");
+    trybuild::TestCases::new().pass("tests/data/default_max_length/*.rs");
+}